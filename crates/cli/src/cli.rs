@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::format::Format;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "ps-hash",
+    version,
+    about = "Hash files, and check, repair, or convert hashes."
+)]
+pub struct Cli {
+    /// Print one JSON object per result instead of text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Hash files, printing `<hash>  <path>` lines
+    Hash(HashArgs),
+    /// Verify files against manifests of `<hash>  <path>` lines
+    Check(CheckArgs),
+    /// Repair damaged hashes, reporting what was corrected
+    Repair(HashInputArgs),
+    /// Convert hashes between representations
+    Convert(ConvertArgs),
+    /// Print the maximum data length embedded in hashes
+    MaxLen(HashInputArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct HashArgs {
+    /// Files to hash; `-` or none reads standard input
+    pub files: Vec<PathBuf>,
+
+    /// Representation to print
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+#[derive(Debug, Args)]
+pub struct CheckArgs {
+    /// Manifests to check; `-` or none reads standard input
+    pub manifests: Vec<PathBuf>,
+
+    /// Print only failures
+    #[arg(short, long)]
+    pub quiet: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct HashInputArgs {
+    /// Hashes in any representation; none reads one per line from standard
+    /// input
+    pub hashes: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+    /// Representation to convert to
+    #[arg(short, long, value_enum)]
+    pub to: Format,
+
+    #[command(flatten)]
+    pub input: HashInputArgs,
+}
+
+impl HashInputArgs {
+    /// Returns the hashes given as arguments, or the non-empty lines of
+    /// standard input if there are none.
+    pub fn hashes(&self) -> std::io::Result<Vec<String>> {
+        if !self.hashes.is_empty() {
+            return Ok(self.hashes.clone());
+        }
+
+        std::io::stdin()
+            .lines()
+            .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+            .collect()
+    }
+}
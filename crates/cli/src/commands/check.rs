@@ -0,0 +1,213 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+use crate::{
+    cli::CheckArgs,
+    format::parse,
+    output::{Output, Record},
+    status::Status,
+};
+
+use super::hash::hash_file;
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Verdict {
+    Ok,
+    Failed,
+    Unreadable,
+}
+
+#[derive(Serialize)]
+struct Checked {
+    path: String,
+    status: Verdict,
+    /// Why an unreadable file could not be read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Record for Checked {
+    fn text(&self) -> String {
+        match self.status {
+            Verdict::Ok => format!("{}: OK", self.path),
+            Verdict::Failed => format!("{}: FAILED", self.path),
+            Verdict::Unreadable => format!("{}: FAILED open or read", self.path),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    failed: usize,
+    unreadable: usize,
+    malformed: usize,
+}
+
+pub fn run(args: &CheckArgs, output: &Output) -> Status {
+    let stdin = [PathBuf::from("-")];
+    let manifests = if args.manifests.is_empty() {
+        &stdin[..]
+    } else {
+        &args.manifests
+    };
+
+    let mut tally = Tally::default();
+    let mut status = Status::Success;
+
+    for manifest in manifests {
+        let name = manifest.display().to_string();
+        let from_stdin = manifest == Path::new("-");
+
+        let contents = match read_manifest(manifest) {
+            Ok(contents) => contents,
+            Err(error) => {
+                output.fail(&name, &error);
+                status = status.max(Status::Error);
+                continue;
+            }
+        };
+
+        for (index, line) in contents.lines().enumerate() {
+            let location = format!("{name}:{}", index + 1);
+
+            status = status.max(check_line(
+                line, &location, from_stdin, args, output, &mut tally,
+            ));
+        }
+    }
+
+    if tally.malformed > 0 {
+        output.warn(&format_args!(
+            "WARNING: {} {} improperly formatted",
+            tally.malformed,
+            plural(tally.malformed, "line is", "lines are")
+        ));
+    }
+
+    if tally.unreadable > 0 {
+        output.warn(&format_args!(
+            "WARNING: {} listed {} could not be read",
+            tally.unreadable,
+            plural(tally.unreadable, "file", "files")
+        ));
+    }
+
+    if tally.failed > 0 {
+        output.warn(&format_args!(
+            "WARNING: {} computed {} did NOT match",
+            tally.failed,
+            plural(tally.failed, "hash", "hashes")
+        ));
+    }
+
+    status
+}
+
+/// Checks one manifest line. `from_stdin` tells whether the manifest was read
+/// from standard input, which then has nothing left for a `-` entry.
+fn check_line(
+    line: &str,
+    location: &str,
+    from_stdin: bool,
+    args: &CheckArgs,
+    output: &Output,
+    tally: &mut Tally,
+) -> Status {
+    let line = line.trim_end();
+
+    if line.is_empty() || line.starts_with('#') {
+        return Status::Success;
+    }
+
+    let Some((expected, path)) = split_entry(line) else {
+        output.fail(location, &"improperly formatted line");
+        tally.malformed += 1;
+        return Status::InvalidHash;
+    };
+
+    let expected = match parse(expected) {
+        Ok(parsed) => parsed.hash,
+        Err(error) => {
+            output.fail(location, &error);
+            tally.malformed += 1;
+            return Status::InvalidHash;
+        }
+    };
+
+    let actual = if from_stdin && path == "-" {
+        Err("standard input holds the manifest, so it cannot be checked".to_owned())
+    } else {
+        hash_file(Path::new(path)).map_err(|error| error.to_string())
+    };
+
+    let (verdict, status, error) = match actual {
+        Ok(actual) if actual == expected => (Verdict::Ok, Status::Success, None),
+        Ok(_) => {
+            tally.failed += 1;
+            (Verdict::Failed, Status::Mismatch, None)
+        }
+        Err(error) => {
+            output.warn(&format_args!("{path}: {error}"));
+            tally.unreadable += 1;
+            (Verdict::Unreadable, Status::Error, Some(error))
+        }
+    };
+
+    if verdict != Verdict::Ok || !args.quiet {
+        output.emit(&Checked {
+            path: path.to_owned(),
+            status: verdict,
+            error,
+        });
+    }
+
+    status
+}
+
+/// Splits a `<hash>  <path>` or `<hash> *<path>` manifest line.
+fn split_entry(line: &str) -> Option<(&str, &str)> {
+    let (hash, rest) = line.split_once(' ')?;
+    let path = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*'))?;
+
+    (!hash.is_empty() && !path.is_empty()).then_some((hash, path))
+}
+
+fn read_manifest(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        io::read_to_string(io::stdin().lock())
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+const fn plural<'a>(count: usize, singular: &'a str, plural: &'a str) -> &'a str {
+    if count == 1 {
+        singular
+    } else {
+        plural
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_entry;
+
+    #[test]
+    fn splits_text_and_binary_entries() {
+        assert_eq!(split_entry("HASH  a file"), Some(("HASH", "a file")));
+        assert_eq!(split_entry("HASH *a file"), Some(("HASH", "a file")));
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        assert_eq!(split_entry("HASH"), None);
+        assert_eq!(split_entry("HASH path"), None);
+        assert_eq!(split_entry("HASH  "), None);
+        assert_eq!(split_entry(" HASH  path"), None);
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::{
+    cli::ConvertArgs,
+    format::Format,
+    output::{Output, Record},
+    status::Status,
+};
+
+use super::for_each_hash;
+
+#[derive(Serialize)]
+struct Converted {
+    input: String,
+    output: String,
+    format: Format,
+}
+
+impl Record for Converted {
+    fn text(&self) -> String {
+        self.output.clone()
+    }
+}
+
+pub fn run(args: &ConvertArgs, output: &Output) -> Status {
+    for_each_hash(&args.input, output, |input, parsed| {
+        output.emit(&Converted {
+            input: input.to_owned(),
+            output: args.to.encode(&parsed.hash),
+            format: args.to,
+        });
+    })
+}
@@ -0,0 +1,69 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use ps_hash::{Hash, HashReadError};
+use serde::Serialize;
+
+use crate::{
+    cli::HashArgs,
+    format::Format,
+    output::{Output, Record},
+    status::Status,
+};
+
+#[derive(Serialize)]
+struct Hashed {
+    path: String,
+    hash: String,
+    format: Format,
+}
+
+impl Record for Hashed {
+    fn text(&self) -> String {
+        format!("{}  {}", self.hash, self.path)
+    }
+}
+
+pub fn run(args: &HashArgs, output: &Output) -> Status {
+    let stdin = [PathBuf::from("-")];
+    let files = if args.files.is_empty() {
+        &stdin[..]
+    } else {
+        &args.files
+    };
+
+    files
+        .iter()
+        .map(|path| {
+            let name = path.display().to_string();
+
+            match hash_file(path) {
+                Ok(hash) => {
+                    output.emit(&Hashed {
+                        path: name,
+                        hash: args.format.encode(&hash),
+                        format: args.format,
+                    });
+
+                    Status::Success
+                }
+                Err(error) => {
+                    output.fail(&name, &error);
+                    Status::Error
+                }
+            }
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// Hashes the file at `path`, or standard input if `path` is `-`.
+pub fn hash_file(path: &Path) -> Result<Hash, HashReadError> {
+    if path == Path::new("-") {
+        Hash::from_reader(io::stdin().lock())
+    } else {
+        Hash::from_path(path)
+    }
+}
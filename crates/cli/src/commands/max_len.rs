@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::{
+    cli::HashInputArgs,
+    output::{Output, Record},
+    status::Status,
+};
+
+use super::for_each_hash;
+
+#[derive(Serialize)]
+struct MaxLen {
+    hash: String,
+    data_max_len: usize,
+}
+
+impl Record for MaxLen {
+    fn text(&self) -> String {
+        self.data_max_len.to_string()
+    }
+}
+
+pub fn run(args: &HashInputArgs, output: &Output) -> Status {
+    for_each_hash(args, output, |_, parsed| {
+        output.emit(&MaxLen {
+            hash: parsed.hash.to_crockford(),
+            data_max_len: parsed.hash.data_max_len().to_usize(),
+        });
+    })
+}
@@ -0,0 +1,47 @@
+pub mod check;
+pub mod convert;
+pub mod hash;
+pub mod max_len;
+pub mod repair;
+
+use crate::{
+    cli::HashInputArgs,
+    format::{parse, Parsed},
+    output::Output,
+    status::Status,
+};
+
+/// Parses every hash input, handing each one that parses to `handle` along
+/// with its trimmed text.
+pub fn for_each_hash(
+    args: &HashInputArgs,
+    output: &Output,
+    mut handle: impl FnMut(&str, Parsed),
+) -> Status {
+    let inputs = match args.hashes() {
+        Ok(inputs) => inputs,
+        Err(error) => {
+            output.fail("-", &error);
+            return Status::Error;
+        }
+    };
+
+    inputs
+        .iter()
+        .map(|input| {
+            let input = input.trim();
+
+            match parse(input) {
+                Ok(parsed) => {
+                    handle(input, parsed);
+                    Status::Success
+                }
+                Err(error) => {
+                    output.fail(input, &error);
+                    Status::InvalidHash
+                }
+            }
+        })
+        .max()
+        .unwrap_or_default()
+}
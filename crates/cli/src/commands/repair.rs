@@ -0,0 +1,73 @@
+use std::ops::Range;
+
+use serde::Serialize;
+
+use crate::{
+    cli::HashInputArgs,
+    format::representation_name,
+    output::{Output, Record},
+    status::Status,
+};
+
+use super::for_each_hash;
+
+#[derive(Serialize)]
+struct Repaired {
+    input: String,
+    hash: String,
+    representation: String,
+    truncated: bool,
+    corrections: Vec<Corrected>,
+}
+
+/// A corrected byte, by its offset in the internal representation and the
+/// range of input characters it was decoded from.
+#[derive(Serialize)]
+struct Corrected {
+    offset: usize,
+    input: Range<usize>,
+}
+
+impl Record for Repaired {
+    fn text(&self) -> String {
+        self.hash.clone()
+    }
+}
+
+pub fn run(args: &HashInputArgs, output: &Output) -> Status {
+    for_each_hash(args, output, |input, parsed| {
+        let repaired = Repaired {
+            input: input.to_owned(),
+            hash: parsed.hash.to_crockford(),
+            representation: representation_name(parsed.report.representation()).to_owned(),
+            truncated: parsed.report.is_truncated(),
+            corrections: parsed
+                .report
+                .corrections()
+                .iter()
+                .map(|correction| Corrected {
+                    offset: correction.offset(),
+                    input: correction.input(),
+                })
+                .collect(),
+        };
+
+        output.emit(&repaired);
+
+        for correction in &repaired.corrections {
+            let Range { start, end } = correction.input;
+
+            if start == end {
+                output.warn(&format_args!(
+                    "{input}: restored byte {} past the end of the input",
+                    correction.offset
+                ));
+            } else {
+                output.warn(&format_args!(
+                    "{input}: corrected byte {} from characters {start}..{end}",
+                    correction.offset
+                ));
+            }
+        }
+    })
+}
@@ -0,0 +1,89 @@
+use clap::ValueEnum;
+use ps_hash::{Hash, HashValidationError, Representation, ValidationReport};
+use serde::Serialize;
+
+/// A representation the tool prints hashes in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Crockford Base32, the canonical form
+    #[default]
+    Crockford,
+    /// Unpadded base64url
+    Base64,
+    /// Lowercase hexadecimal of the 48 raw bytes
+    Hex,
+}
+
+impl Format {
+    pub fn encode(self, hash: &Hash) -> String {
+        match self {
+            Self::Crockford => hash.to_crockford(),
+            Self::Base64 => hash.to_base64(),
+            Self::Hex => hash.to_hex(),
+        }
+    }
+}
+
+/// Returns the name the tool reports a parsed representation under.
+pub const fn representation_name(representation: Representation) -> &'static str {
+    match representation {
+        Representation::Binary => "binary",
+        Representation::Compact => "compact",
+        Representation::Base64 => "base64",
+        Representation::Crockford => "crockford",
+        Representation::Hex => "hex",
+        _ => "unknown",
+    }
+}
+
+/// A hash parsed from user input.
+pub struct Parsed {
+    pub hash: Hash,
+    pub report: ValidationReport,
+}
+
+/// Parses and repairs a hash in any representation [`Hash::validate`]
+/// accepts.
+pub fn parse(input: &str) -> Result<Parsed, HashValidationError> {
+    let (hash, report) = Hash::validate_with_report(input.trim())?;
+
+    Ok(Parsed { hash, report })
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use ps_hash::Hash;
+
+    use super::{parse, representation_name, Format};
+
+    #[test]
+    fn every_format_parses_back() {
+        let hash = Hash::hash(b"formats").expect("hashing should succeed");
+
+        for format in [Format::Crockford, Format::Base64, Format::Hex] {
+            let parsed = parse(&format.encode(&hash)).expect("parsing should succeed");
+
+            assert_eq!(parsed.hash, hash, "{format:?}");
+            assert_eq!(parsed.report.corrected_bytes(), 0);
+            assert_eq!(
+                representation_name(parsed.report.representation()),
+                format!("{format:?}").to_lowercase()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_ignores_surrounding_whitespace() {
+        let hash = Hash::hash(b"whitespace").expect("hashing should succeed");
+        let parsed = parse(&format!("  {hash}\n")).expect("parsing should succeed");
+
+        assert_eq!(parsed.hash, hash);
+    }
+
+    #[test]
+    fn parse_rejects_garbage() {
+        assert!(parse("not a hash").is_err());
+    }
+}
@@ -0,0 +1,40 @@
+//! The `ps-hash` command-line tool.
+//!
+//! Exit codes are stable for scripting:
+//!
+//! | code | meaning                                            |
+//! |------|----------------------------------------------------|
+//! | 0    | success                                            |
+//! | 1    | a computed hash did not match                      |
+//! | 2    | usage or I/O error                                 |
+//! | 3    | a hash was invalid beyond repair, or a line malformed |
+
+mod cli;
+mod commands;
+mod format;
+mod output;
+mod status;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::{
+    cli::{Cli, Command},
+    output::Output,
+};
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let output = Output::new(cli.json);
+
+    let status = match &cli.command {
+        Command::Hash(args) => commands::hash::run(args, &output),
+        Command::Check(args) => commands::check::run(args, &output),
+        Command::Repair(args) => commands::repair::run(args, &output),
+        Command::Convert(args) => commands::convert::run(args, &output),
+        Command::MaxLen(args) => commands::max_len::run(args, &output),
+    };
+
+    status.into()
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// A result the tool prints, either as a line of text or as a JSON object.
+pub trait Record: Serialize {
+    fn text(&self) -> String;
+}
+
+/// Prints results as text or as JSON Lines.
+///
+/// In text mode, results go to standard output and failures to standard
+/// error. In JSON mode, both go to standard output, one object per line,
+/// failures carrying an `error` field.
+pub struct Output {
+    json: bool,
+}
+
+#[derive(Serialize)]
+struct Failure<'a> {
+    input: &'a str,
+    error: String,
+}
+
+impl Output {
+    pub const fn new(json: bool) -> Self {
+        Self { json }
+    }
+
+    pub fn emit(&self, record: &impl Record) {
+        if !self.json {
+            println!("{}", record.text());
+            return;
+        }
+
+        match serde_json::to_string(record) {
+            Ok(line) => println!("{line}"),
+            Err(error) => eprintln!("ps-hash: {error}"),
+        }
+    }
+
+    /// Reports that processing `input` failed.
+    pub fn fail(&self, input: &str, error: &dyn Display) {
+        if self.json {
+            self.emit(&Failure {
+                input,
+                error: error.to_string(),
+            });
+        } else {
+            eprintln!("ps-hash: {input}: {error}");
+        }
+    }
+
+    /// Prints a diagnostic to standard error, in text mode only.
+    pub fn warn(&self, message: &dyn Display) {
+        if !self.json {
+            eprintln!("ps-hash: {message}");
+        }
+    }
+}
+
+impl Record for Failure<'_> {
+    fn text(&self) -> String {
+        format!("{}: {}", self.input, self.error)
+    }
+}
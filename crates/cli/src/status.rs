@@ -0,0 +1,33 @@
+use std::process::ExitCode;
+
+/// The outcome of a command, ordered by severity.
+///
+/// A command that processes several inputs exits with the most severe status
+/// any of them produced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    #[default]
+    Success = 0,
+    Mismatch = 1,
+    Error = 2,
+    InvalidHash = 3,
+}
+
+impl From<Status> for ExitCode {
+    fn from(status: Status) -> Self {
+        Self::from(status as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+
+    #[test]
+    fn most_severe_status_wins() {
+        let statuses = [Status::Success, Status::InvalidHash, Status::Mismatch];
+
+        assert_eq!(statuses.into_iter().max(), Some(Status::InvalidHash));
+        assert_eq!(Status::Success.max(Status::Error), Status::Error);
+    }
+}
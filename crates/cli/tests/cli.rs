@@ -0,0 +1,247 @@
+#![allow(clippy::expect_used)]
+
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Output, Stdio},
+};
+
+use ps_hash::Hash;
+
+fn ps_hash(dir: &Path, args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ps-hash"))
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("the binary should start");
+
+    child
+        .stdin
+        .take()
+        .expect("stdin should be piped")
+        .write_all(stdin)
+        .expect("writing stdin should succeed");
+
+    child.wait_with_output().expect("the binary should finish")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).expect("stdout should be UTF-8")
+}
+
+fn hash(data: &[u8]) -> Hash {
+    Hash::hash(data).expect("hashing should succeed")
+}
+
+#[test]
+fn hashes_files_and_stdin() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    fs::write(dir.path().join("file"), b"file contents").expect("writing should succeed");
+
+    let output = ps_hash(dir.path(), &["hash", "file", "-"], b"stdin contents");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        format!(
+            "{}  file\n{}  -\n",
+            hash(b"file contents"),
+            hash(b"stdin contents")
+        )
+    );
+}
+
+#[test]
+fn hashes_in_every_format() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    let expected = hash(b"formats");
+
+    let base64 = ps_hash(dir.path(), &["hash", "--format", "base64"], b"formats");
+    let hex = ps_hash(dir.path(), &["hash", "--format", "hex"], b"formats");
+
+    assert_eq!(stdout(&base64), format!("{}  -\n", expected.to_base64()));
+    assert_eq!(
+        stdout(&hex),
+        format!(
+            "{}  -\n",
+            expected
+                .as_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>()
+        )
+    );
+}
+
+#[test]
+fn checks_a_manifest() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    fs::write(dir.path().join("good"), b"good").expect("writing should succeed");
+    fs::write(dir.path().join("bad"), b"tampered").expect("writing should succeed");
+
+    let manifest = format!(
+        "{}  good\n{} *bad\n{}  missing\n",
+        hash(b"good"),
+        hash(b"bad"),
+        hash(b"missing")
+    );
+
+    let output = ps_hash(dir.path(), &["check"], manifest.as_bytes());
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        stdout(&output),
+        "good: OK\nbad: FAILED\nmissing: FAILED open or read\n"
+    );
+
+    let manifest = format!("{}  good\n{}  bad\n", hash(b"good"), hash(b"bad"));
+    let output = ps_hash(dir.path(), &["check", "--quiet"], manifest.as_bytes());
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "bad: FAILED\n");
+}
+
+#[test]
+fn check_reports_why_files_are_unreadable_in_json() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    let manifest = format!("{}  missing\n", hash(b"missing"));
+
+    let output = ps_hash(dir.path(), &["--json", "check"], manifest.as_bytes());
+
+    assert_eq!(output.status.code(), Some(2));
+
+    let record: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("the output should be JSON");
+
+    assert_eq!(record["path"], "missing");
+    assert_eq!(record["status"], "unreadable");
+    assert!(record["error"].is_string());
+}
+
+#[test]
+fn check_rejects_stdin_entries_in_a_manifest_read_from_stdin() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    let manifest = format!("{}  -\n", hash(b""));
+
+    let output = ps_hash(dir.path(), &["check"], manifest.as_bytes());
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout(&output), "-: FAILED open or read\n");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("standard input holds the manifest"));
+}
+
+#[test]
+fn check_accepts_its_own_output() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    fs::write(dir.path().join("one"), b"one").expect("writing should succeed");
+    fs::write(dir.path().join("two"), b"two").expect("writing should succeed");
+
+    let manifest = ps_hash(dir.path(), &["hash", "one", "two"], b"");
+    let output = ps_hash(dir.path(), &["check"], &manifest.stdout);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "one: OK\ntwo: OK\n");
+}
+
+#[test]
+fn check_reports_malformed_lines() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+
+    let output = ps_hash(dir.path(), &["check"], b"not a manifest\n");
+
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("improperly formatted"));
+}
+
+#[test]
+fn repairs_and_reports_corrections() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    let original = hash(b"repair");
+    let mut damaged = original.to_crockford().into_bytes();
+
+    damaged[10] = if damaged[10] == b'A' { b'B' } else { b'A' };
+
+    let damaged = String::from_utf8(damaged).expect("the damaged hash should be UTF-8");
+    let output = ps_hash(dir.path(), &["--json", "repair", &damaged], b"");
+
+    assert_eq!(output.status.code(), Some(0));
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("the output should be JSON");
+
+    assert_eq!(report["hash"], original.to_crockford());
+    assert_eq!(report["representation"], "crockford");
+    assert_eq!(report["truncated"], false);
+
+    let corrections = report["corrections"]
+        .as_array()
+        .expect("corrections should be an array");
+
+    assert!(!corrections.is_empty());
+    assert!(corrections.iter().all(|correction| {
+        correction["input"]["start"].as_u64() <= Some(10)
+            && correction["input"]["end"].as_u64() > Some(10)
+    }));
+}
+
+#[test]
+fn rejects_unrepairable_hashes() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+
+    let output = ps_hash(dir.path(), &["--json", "repair", "garbage"], b"");
+
+    assert_eq!(output.status.code(), Some(3));
+
+    let failure: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("the output should be JSON");
+
+    assert_eq!(failure["input"], "garbage");
+    assert!(failure["error"].is_string());
+}
+
+#[test]
+fn converts_between_representations() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    let original = hash(b"convert");
+
+    let hex = ps_hash(
+        dir.path(),
+        &["convert", "--to", "hex"],
+        original.to_base64().as_bytes(),
+    );
+    let hex = stdout(&hex);
+
+    assert_eq!(hex.trim().len(), 96);
+
+    let crockford = ps_hash(
+        dir.path(),
+        &["convert", "--to", "crockford", hex.trim()],
+        b"",
+    );
+
+    assert_eq!(stdout(&crockford), format!("{original}\n"));
+}
+
+#[test]
+fn prints_data_max_len() {
+    let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+    let original = hash(b"length");
+
+    let output = ps_hash(
+        dir.path(),
+        &["--json", "max-len", &original.to_crockford()],
+        b"",
+    );
+
+    let record: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("the output should be JSON");
+
+    assert_eq!(
+        record["data_max_len"].as_u64(),
+        u64::try_from(original.data_max_len().to_usize()).ok()
+    );
+}
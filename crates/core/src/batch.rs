@@ -0,0 +1,100 @@
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, Map, ParallelIterator};
+
+use crate::{Hash, HashError};
+
+/// Hashes each of `inputs`, returning the results in the same order.
+///
+/// With the `rayon` feature enabled, the inputs are spread across the rayon
+/// thread pool. Each result equals [`Hash::hash`] on the corresponding input.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_core::{hash_many, Hash};
+///
+/// let hashes = hash_many(&[b"first", b"second"]);
+///
+/// assert_eq!(hashes[1], Hash::hash(b"second"));
+/// ```
+#[must_use]
+pub fn hash_many(inputs: &[&[u8]]) -> Vec<Result<Hash, HashError>> {
+    #[cfg(feature = "rayon")]
+    return inputs.par_iter().map(Hash::hash).collect();
+
+    #[cfg(not(feature = "rayon"))]
+    return inputs.iter().map(Hash::hash).collect();
+}
+
+/// Extends parallel iterators over byte containers with [`hash_each`].
+///
+/// [`hash_each`]: ParallelHashIterator::hash_each
+#[cfg(feature = "rayon")]
+pub trait ParallelHashIterator: ParallelIterator<Item: AsRef<[u8]>> {
+    /// Hashes every item, preserving the iterator's order where it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps_hash_core::{Hash, ParallelHashIterator};
+    /// use rayon::prelude::*;
+    ///
+    /// let inputs = vec![b"a".to_vec(), b"b".to_vec()];
+    /// let hashes: Vec<_> = inputs.par_iter().hash_each().collect();
+    ///
+    /// assert_eq!(hashes[0], Hash::hash(b"a"));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn hash_each(self) -> Map<Self, fn(Self::Item) -> Result<Hash, HashError>> {
+        self.map(hash_item::<Self::Item>)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<I: ParallelIterator<Item: AsRef<[u8]>>> ParallelHashIterator for I {}
+
+#[cfg(feature = "rayon")]
+fn hash_item<T: AsRef<[u8]>>(item: T) -> Result<Hash, HashError> {
+    Hash::hash(item)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::hash_many;
+    use crate::Hash;
+
+    #[test]
+    fn hash_many_matches_hash() {
+        let inputs: Vec<Vec<u8>> = (0u32..1000).map(|i| i.to_le_bytes().repeat(7)).collect();
+        let slices: Vec<&[u8]> = inputs.iter().map(Vec::as_slice).collect();
+
+        let hashes = hash_many(&slices);
+
+        assert_eq!(hashes.len(), inputs.len());
+
+        for (input, hash) in inputs.iter().zip(hashes) {
+            assert_eq!(hash, Hash::hash(input));
+        }
+    }
+
+    #[test]
+    fn hash_many_handles_no_inputs() {
+        assert!(hash_many(&[]).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn hash_each_matches_hash() {
+        use rayon::prelude::*;
+
+        use super::ParallelHashIterator;
+
+        let inputs: Vec<String> = (0..1000).map(|i| format!("item {i}")).collect();
+        let hashes: Vec<_> = inputs.par_iter().hash_each().collect();
+
+        for (input, hash) in inputs.iter().zip(hashes) {
+            assert_eq!(hash, Hash::hash(input));
+        }
+    }
+}
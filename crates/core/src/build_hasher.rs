@@ -0,0 +1,192 @@
+use std::hash::{BuildHasher, DefaultHasher, Hasher, RandomState};
+
+use crate::Hash;
+
+/// A [`std::collections::HashMap`] keyed by [`Hash`](struct@Hash), using
+/// [`HashBuildHasher`].
+pub type HashMap<V> = std::collections::HashMap<Hash, V, HashBuildHasher>;
+
+/// A [`std::collections::HashSet`] of [`Hash`](struct@Hash) values, using
+/// [`HashBuildHasher`].
+pub type HashSet = std::collections::HashSet<Hash, HashBuildHasher>;
+
+/// A [`BuildHasher`] for maps and sets keyed by [`Hash`](struct@Hash).
+///
+/// A hash's digest is already uniformly random, so by default the map hash is
+/// simply its first 8 digest bytes, instead of running all
+/// [`HASH_SIZE_BIN`](crate::HASH_SIZE_BIN) bytes through SipHash.
+///
+/// That is only safe while the keys are honest. Anyone able to choose which
+/// hashes are inserted can grind for digests sharing their low bits and
+/// degrade the map to linear probing, so maps holding untrusted keys should
+/// use [`HashBuildHasher::keyed`], which runs every byte through SipHash
+/// with random keys.
+///
+/// The hasher is meant for [`Hash`](struct@Hash) keys only. Other keys still
+/// work, but are hashed weakly.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_core::{Hash, HashBuildHasher, HashMap};
+///
+/// let mut sizes = HashMap::default();
+/// let mut untrusted = HashMap::with_hasher(HashBuildHasher::keyed());
+///
+/// sizes.insert(Hash::hash(b"content")?, 7);
+/// untrusted.insert(Hash::hash(b"upload")?, 6);
+///
+/// assert_eq!(sizes[&Hash::hash(b"content")?], 7);
+/// assert_eq!(untrusted[&Hash::hash(b"upload")?], 6);
+/// # Ok::<(), ps_hash_core::HashError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HashBuildHasher {
+    key: Option<RandomState>,
+}
+
+/// The [`Hasher`] built by [`HashBuildHasher`].
+#[derive(Clone, Debug, Default)]
+pub struct HashHasher {
+    value: u64,
+    keyed: Option<DefaultHasher>,
+}
+
+impl HashBuildHasher {
+    /// Creates a build hasher that uses the digest bytes as they are.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a build hasher that mixes the digest bytes with SipHash under
+    /// random keys, so that adversarially chosen hashes cannot collide.
+    #[must_use]
+    pub fn keyed() -> Self {
+        Self {
+            key: Some(RandomState::new()),
+        }
+    }
+
+    /// Returns whether this build hasher was created by
+    /// [`HashBuildHasher::keyed`] and so mixes the digest bytes.
+    #[must_use]
+    pub const fn is_keyed(&self) -> bool {
+        self.key.is_some()
+    }
+}
+
+impl BuildHasher for HashBuildHasher {
+    type Hasher = HashHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        HashHasher {
+            value: 0,
+            keyed: self.key.as_ref().map(RandomState::build_hasher),
+        }
+    }
+}
+
+impl Hasher for HashHasher {
+    /// Takes the first 8 bytes of `bytes`, which for a
+    /// [`Hash`](struct@Hash) are the start of its digest, or when keyed,
+    /// all of them.
+    fn write(&mut self, bytes: &[u8]) {
+        // The remaining bytes are as attacker-controlled as the first 8, so
+        // the keyed hasher has to see them all.
+        if let Some(hasher) = &mut self.keyed {
+            hasher.write(bytes);
+            return;
+        }
+
+        let mut word = [0u8; 8];
+        let len = bytes.len().min(word.len());
+
+        word[..len].copy_from_slice(&bytes[..len]);
+
+        // A single write, as for a hash, leaves the word unchanged.
+        self.value = self.value.rotate_left(29) ^ u64::from_le_bytes(word);
+    }
+
+    fn finish(&self) -> u64 {
+        match &self.keyed {
+            Some(hasher) => hasher.finish(),
+            None => self.value,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::hash::BuildHasher;
+
+    use super::{HashBuildHasher, HashMap, HashSet};
+    use crate::{Hash, PackedInt};
+
+    fn hash(data: &[u8]) -> Hash {
+        Hash::hash(data).expect("hashing should succeed")
+    }
+
+    #[test]
+    fn uses_the_first_digest_bytes() {
+        let key = hash(b"identity");
+        let expected = u64::from_le_bytes(
+            key.digest()[..8]
+                .try_into()
+                .expect("the digest should have 8 bytes"),
+        );
+
+        assert_eq!(HashBuildHasher::new().hash_one(key), expected);
+    }
+
+    #[test]
+    fn keyed_mode_differs_between_instances() {
+        let key = hash(b"keyed");
+        let first = HashBuildHasher::keyed();
+        let second = HashBuildHasher::keyed();
+
+        assert!(first.is_keyed());
+        assert!(!HashBuildHasher::new().is_keyed());
+        assert_eq!(first.hash_one(key), first.hash_one(key));
+        assert_ne!(first.hash_one(key), second.hash_one(key));
+    }
+
+    #[test]
+    fn keyed_mode_hashes_past_the_first_digest_bytes() {
+        let mut digest = [7u8; 32];
+        let first = Hash::from_parts(&digest, PackedInt::from_usize(1))
+            .expect("building a hash should succeed");
+
+        digest[31] = 8;
+
+        let second = Hash::from_parts(&digest, PackedInt::from_usize(1))
+            .expect("building a hash should succeed");
+        let keyed = HashBuildHasher::keyed();
+
+        assert_eq!(
+            HashBuildHasher::new().hash_one(first),
+            HashBuildHasher::new().hash_one(second)
+        );
+        assert_ne!(keyed.hash_one(first), keyed.hash_one(second));
+    }
+
+    #[test]
+    fn map_and_set_aliases_work() {
+        let mut map = HashMap::default();
+        let mut set = HashSet::with_hasher(HashBuildHasher::keyed());
+
+        for i in 0u32..1000 {
+            let key = hash(&i.to_le_bytes());
+
+            map.insert(key, i);
+            set.insert(key);
+        }
+
+        assert_eq!(map.len(), 1000);
+        assert_eq!(set.len(), 1000);
+        assert_eq!(map[&hash(&7u32.to_le_bytes())], 7);
+        assert!(set.contains(&hash(&999u32.to_le_bytes())));
+        assert!(!set.contains(&hash(b"absent")));
+    }
+}
@@ -39,6 +39,9 @@ pub const HASH_SIZE_CROCKFORD: usize = encoded_size(CROCKFORD_BITS);
 /// Size of the base64url representation, in characters.
 pub const HASH_SIZE_BASE64: usize = encoded_size(BASE64_BITS);
 
+/// Size of the hexadecimal representation, in characters.
+pub const HASH_SIZE_HEX: usize = encoded_size(HEX_BITS);
+
 /// Shortest binary input [`Hash::validate`] accepts.
 ///
 /// [`Hash::validate`]: crate::Hash::validate
@@ -55,11 +58,19 @@ pub const MIN_RECOVERABLE_CROCKFORD: usize =
 /// [`Hash::validate`]: crate::Hash::validate
 pub const MIN_RECOVERABLE_BASE64: usize = HASH_SIZE_BASE64 - recoverable_truncation(BASE64_BITS);
 
+/// Shortest hexadecimal input [`Hash::validate`] accepts.
+///
+/// [`Hash::validate`]: crate::Hash::validate
+pub const MIN_RECOVERABLE_HEX: usize = HASH_SIZE_HEX - recoverable_truncation(HEX_BITS);
+
 /// Bits carried by one Crockford Base32 character.
-const CROCKFORD_BITS: usize = 5;
+pub(crate) const CROCKFORD_BITS: usize = 5;
 
 /// Bits carried by one base64url character.
-const BASE64_BITS: usize = 6;
+pub(crate) const BASE64_BITS: usize = 6;
+
+/// Bits carried by one hexadecimal character.
+pub(crate) const HEX_BITS: usize = 4;
 
 /// Number of characters needed to carry [`HASH_SIZE_BIN`] bytes.
 const fn encoded_size(bits_per_char: usize) -> usize {
@@ -73,7 +84,7 @@ const fn recoverable_truncation(bits_per_char: usize) -> usize {
 }
 
 /// [`Hash::validate`] dispatches on input length alone, so the ranges accepted
-/// for the three representations must not overlap.
+/// for the four representations must not overlap.
 ///
 /// [`Hash::validate`]: crate::Hash::validate
 const _: () = {
@@ -84,6 +95,9 @@ const _: () = {
     assert!(HASH_SIZE_BASE64 < MIN_RECOVERABLE_CROCKFORD);
 
     assert!(MIN_RECOVERABLE_CROCKFORD <= HASH_SIZE_CROCKFORD);
+    assert!(HASH_SIZE_CROCKFORD < MIN_RECOVERABLE_HEX);
+
+    assert!(MIN_RECOVERABLE_HEX <= HASH_SIZE_HEX);
 };
 
 /// The compact representation is accepted through the binary range, so it must
@@ -93,18 +107,19 @@ const _: () = {
     assert!(HASH_SIZE_COMPACT <= HASH_SIZE_BIN);
 };
 
-/// Both encodings must carry the whole internal representation.
+/// Every encoding must carry the whole internal representation.
 const _: () = {
     assert!(HASH_SIZE_CROCKFORD * CROCKFORD_BITS >= HASH_SIZE_BIN * 8);
     assert!(HASH_SIZE_BASE64 * BASE64_BITS >= HASH_SIZE_BIN * 8);
+    assert!(HASH_SIZE_HEX * HEX_BITS >= HASH_SIZE_BIN * 8);
 };
 
 #[cfg(test)]
 mod tests {
     use super::{
         DIGEST_SIZE, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_COMPACT, HASH_SIZE_CROCKFORD,
-        MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD, PARITY,
-        PARITY_OFFSET, PARITY_SIZE, SIZE_SIZE,
+        HASH_SIZE_HEX, MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD,
+        MIN_RECOVERABLE_HEX, PARITY, PARITY_OFFSET, PARITY_SIZE, SIZE_SIZE,
     };
 
     /// Pins every derived constant, so that a change to [`PARITY`] cannot
@@ -120,8 +135,10 @@ mod tests {
         assert_eq!(HASH_SIZE_COMPACT, 42);
         assert_eq!(HASH_SIZE_CROCKFORD, 77);
         assert_eq!(HASH_SIZE_BASE64, 64);
+        assert_eq!(HASH_SIZE_HEX, 96);
         assert_eq!(MIN_RECOVERABLE_BIN, 41);
         assert_eq!(MIN_RECOVERABLE_CROCKFORD, 66);
         assert_eq!(MIN_RECOVERABLE_BASE64, 55);
+        assert_eq!(MIN_RECOVERABLE_HEX, 82);
     }
 }
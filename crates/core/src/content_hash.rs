@@ -0,0 +1,356 @@
+//! Content hashing of structured values.
+//!
+//! [`ContentHash`] gives a value a canonical byte encoding and hashes it, so
+//! that equal values hash alike regardless of how they are laid out in
+//! memory. `#[derive(ContentHash)]` from `ps-hash-macros` implements it for
+//! structs and enums.
+//!
+//! # Encoding
+//!
+//! - Unsigned and signed integers are encoded little-endian at their full
+//!   width; `usize` and `isize` are widened to 64 bits, so that the encoding
+//!   does not depend on the platform.
+//! - `bool` is one byte, 0 or 1, and `char` is its scalar value as a `u32`.
+//! - `f32` and `f64` are their IEEE 754 bits, so `0.0` and `-0.0` differ, as
+//!   do NaNs with different payloads.
+//! - Strings, slices, vectors, arrays and maps are their element count as a
+//!   `u64`, then their elements in order. Maps are only supported for the
+//!   ordered `BTreeMap`.
+//! - `Option` is a 0 byte for `None`, or a 1 byte and the value.
+//! - A [`Hash`](struct@Hash) is its [`HASH_SIZE_BIN`](crate::HASH_SIZE_BIN)
+//!   bytes.
+//! - Tuples, and the fields of derived structs and enum variants, are each
+//!   written with [`encode_field`]: their encoding prefixed with its length
+//!   as a `u64`. An enum variant is preceded by its index as a `u32`.
+//!
+//! All integers in the encoding itself are little-endian.
+//!
+//! # Examples
+//!
+//! ```
+//! use ps_hash_core::{content_hash::encode_field, ContentHash, Hash};
+//!
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! impl ContentHash for Point {
+//!     fn encode_content(&self, out: &mut Vec<u8>) {
+//!         encode_field(out, &self.x);
+//!         encode_field(out, &self.y);
+//!     }
+//! }
+//!
+//! assert_eq!(
+//!     Point { x: 1, y: 2 }.content_hash()?,
+//!     (1i32, 2i32).content_hash()?
+//! );
+//! # Ok::<(), ps_hash_core::HashError>(())
+//! ```
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{hash_inner, Hash, HashError};
+
+/// A value with a canonical byte encoding, and hence a content hash.
+pub trait ContentHash {
+    /// Appends the canonical encoding of `self` to `out`.
+    fn encode_content(&self, out: &mut Vec<u8>);
+
+    /// Hashes the canonical encoding of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Fails only if hashing fails.
+    fn content_hash(&self) -> Result<Hash, HashError> {
+        let mut encoded = Vec::new();
+
+        self.encode_content(&mut encoded);
+
+        Ok(Hash {
+            inner: hash_inner(&encoded)?,
+        })
+    }
+}
+
+/// Appends the encoding of `value` to `out`, prefixed with its length as a
+/// little-endian `u64`.
+pub fn encode_field<T: ContentHash + ?Sized>(out: &mut Vec<u8>, value: &T) {
+    let start = out.len();
+
+    out.extend_from_slice(&[0; 8]);
+    value.encode_content(out);
+
+    let len = (out.len() - start - 8) as u64;
+
+    out[start..start + 8].copy_from_slice(&len.to_le_bytes());
+}
+
+fn encode_len(out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+macro_rules! impl_for_integers {
+    ($($ty:ty),*) => {$(
+        impl ContentHash for $ty {
+            fn encode_content(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_for_integers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl ContentHash for usize {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        (*self as u64).encode_content(out);
+    }
+}
+
+impl ContentHash for isize {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        (*self as i64).encode_content(out);
+    }
+}
+
+impl ContentHash for f32 {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode_content(out);
+    }
+}
+
+impl ContentHash for f64 {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        self.to_bits().encode_content(out);
+    }
+}
+
+impl ContentHash for bool {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        out.push(u8::from(*self));
+    }
+}
+
+impl ContentHash for char {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        u32::from(*self).encode_content(out);
+    }
+}
+
+impl ContentHash for () {
+    fn encode_content(&self, _: &mut Vec<u8>) {}
+}
+
+impl ContentHash for str {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        encode_len(out, self.len());
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl ContentHash for String {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        self.as_str().encode_content(out);
+    }
+}
+
+impl ContentHash for Hash {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.inner);
+    }
+}
+
+impl<T: ContentHash> ContentHash for [T] {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        encode_len(out, self.len());
+
+        for element in self {
+            element.encode_content(out);
+        }
+    }
+}
+
+impl<T: ContentHash, const N: usize> ContentHash for [T; N] {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        self.as_slice().encode_content(out);
+    }
+}
+
+impl<T: ContentHash> ContentHash for Vec<T> {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        self.as_slice().encode_content(out);
+    }
+}
+
+impl<T: ContentHash> ContentHash for BTreeSet<T> {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        encode_len(out, self.len());
+
+        for element in self {
+            element.encode_content(out);
+        }
+    }
+}
+
+impl<K: ContentHash, V: ContentHash> ContentHash for BTreeMap<K, V> {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        encode_len(out, self.len());
+
+        for (key, value) in self {
+            key.encode_content(out);
+            value.encode_content(out);
+        }
+    }
+}
+
+impl<T: ContentHash> ContentHash for Option<T> {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.encode_content(out);
+            }
+        }
+    }
+}
+
+macro_rules! impl_for_pointers {
+    ($($pointer:ident),*) => {$(
+        impl<T: ContentHash + ?Sized> ContentHash for $pointer<T> {
+            fn encode_content(&self, out: &mut Vec<u8>) {
+                (**self).encode_content(out);
+            }
+        }
+    )*};
+}
+
+impl_for_pointers!(Box, Rc, Arc);
+
+impl<T: ContentHash + ?Sized> ContentHash for &T {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        (**self).encode_content(out);
+    }
+}
+
+impl<T: ContentHash + ToOwned + ?Sized> ContentHash for Cow<'_, T> {
+    fn encode_content(&self, out: &mut Vec<u8>) {
+        (**self).encode_content(out);
+    }
+}
+
+macro_rules! impl_for_tuples {
+    ($(($($name:ident),+)),*) => {$(
+        impl<$($name: ContentHash),+> ContentHash for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn encode_content(&self, out: &mut Vec<u8>) {
+                let ($($name,)+) = self;
+
+                $(encode_field(out, $name);)+
+            }
+        }
+    )*};
+}
+
+impl_for_tuples!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H)
+);
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{encode_field, ContentHash};
+    use crate::Hash;
+
+    fn encoded<T: ContentHash + ?Sized>(value: &T) -> Vec<u8> {
+        let mut out = Vec::new();
+
+        value.encode_content(&mut out);
+        out
+    }
+
+    #[test]
+    fn primitives_have_fixed_encodings() {
+        assert_eq!(encoded(&0x0102u16), [2, 1]);
+        assert_eq!(encoded(&-1i8), [0xFF]);
+        assert_eq!(encoded(&7usize), 7u64.to_le_bytes());
+        assert_eq!(encoded(&true), [1]);
+        assert_eq!(encoded(&'A'), 65u32.to_le_bytes());
+        assert_eq!(encoded(&1.0f32), 1.0f32.to_bits().to_le_bytes());
+        assert!(encoded(&()).is_empty());
+    }
+
+    #[test]
+    fn sequences_are_count_prefixed() {
+        assert_eq!(encoded("ab"), [2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b']);
+        assert_eq!(encoded(&vec![1u8, 2]), encoded(&[1u8, 2]));
+        assert_eq!(encoded(&String::from("ab")), encoded("ab"));
+        assert_ne!(
+            encoded(&vec![vec![1u8], vec![]]),
+            encoded(&vec![vec![], vec![1u8]])
+        );
+    }
+
+    #[test]
+    fn options_and_maps_are_unambiguous() {
+        assert_eq!(encoded(&None::<u8>), [0]);
+        assert_eq!(encoded(&Some(5u8)), [1, 5]);
+
+        let map = BTreeMap::from([(2u8, "two"), (1u8, "one")]);
+        let mut expected = 2u64.to_le_bytes().to_vec();
+
+        expected.push(1);
+        expected.extend(encoded("one"));
+        expected.push(2);
+        expected.extend(encoded("two"));
+
+        assert_eq!(encoded(&map), expected);
+    }
+
+    #[test]
+    fn tuple_fields_are_length_prefixed() {
+        let mut expected = Vec::new();
+
+        encode_field(&mut expected, "a");
+        encode_field(&mut expected, &1u8);
+
+        assert_eq!(encoded(&("a", 1u8)), expected);
+        assert_eq!(&expected[..8], &9u64.to_le_bytes());
+        assert_ne!(
+            ("ab", "c").content_hash().expect("hashing should succeed"),
+            ("a", "bc").content_hash().expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn content_hash_hashes_the_encoding() {
+        let value = (String::from("content"), 42u32, Some(true));
+
+        assert_eq!(
+            value.content_hash().expect("hashing should succeed"),
+            Hash::hash(encoded(&value)).expect("hashing should succeed")
+        );
+        assert_eq!(
+            value.content_hash().expect("hashing should succeed"),
+            std::sync::Arc::new(value.clone())
+                .content_hash()
+                .expect("hashing should succeed")
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use hmac::{Hmac, KeyInit, Mac};
 use sha2::{Digest, Sha256};
 
 use crate::DIGEST_SIZE;
 
+/// The SHA-256 block size, to which HMAC zero-pads its key.
+const SHA256_BLOCK_SIZE: usize = 64;
+
 #[inline]
 #[must_use]
 pub fn sha256(data: &[u8]) -> [u8; DIGEST_SIZE] {
@@ -17,8 +21,52 @@ pub fn blake3(data: &[u8]) -> blake3::Hash {
     blake3::hash(data)
 }
 
+#[inline]
+#[must_use]
+pub fn hmac_sha256(key: &[u8; DIGEST_SIZE], data: &[u8]) -> [u8; DIGEST_SIZE] {
+    // HMAC zero-pads short keys itself, so padding here is equivalent and
+    // avoids the fallible slice constructor.
+    let mut padded = [0u8; SHA256_BLOCK_SIZE];
+
+    padded[..DIGEST_SIZE].copy_from_slice(key);
+
+    let mut mac = <Hmac<Sha256> as KeyInit>::new(&padded.into());
+
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+#[inline]
+#[must_use]
+pub fn blake3_keyed(key: &[u8; DIGEST_SIZE], data: &[u8]) -> blake3::Hash {
+    blake3::keyed_hash(key, data)
+}
+
+/// SHA-256 of `data` prefixed with `context`, itself prefixed with its length
+/// as a little-endian `u64`, so that no two contexts share a prefix.
+#[inline]
+#[must_use]
+pub fn sha256_with_context(context: &str, data: &[u8]) -> [u8; DIGEST_SIZE] {
+    let mut hasher = Sha256::new();
+
+    hasher.update((context.len() as u64).to_le_bytes());
+    hasher.update(context);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// BLAKE3 of `data` in key derivation mode, under `context`.
+#[inline]
+#[must_use]
+pub fn blake3_with_context(context: &str, data: &[u8]) -> blake3::Hash {
+    blake3::Hasher::new_derive_key(context)
+        .update(data)
+        .finalize()
+}
+
 #[cfg(test)]
 mod tests {
+    use hmac::{Hmac, KeyInit, Mac};
     use sha2::{Digest, Sha256};
 
     #[test]
@@ -38,4 +86,50 @@ mod tests {
 
         assert_eq!(ours.as_bytes(), expected.as_bytes());
     }
+
+    #[test]
+    fn hmac_sha256_matches_reference_implementation() {
+        let key = [7u8; super::DIGEST_SIZE];
+        let data = b"hmac test";
+        let ours = super::hmac_sha256(&key, data);
+        let mut mac = <Hmac<Sha256> as KeyInit>::new_from_slice(&key)
+            .expect("HMAC should accept any key length");
+
+        mac.update(data);
+
+        let expected: [u8; super::DIGEST_SIZE] = mac.finalize().into_bytes().into();
+
+        assert_eq!(ours, expected);
+    }
+
+    #[test]
+    fn blake3_keyed_matches_reference_implementation() {
+        let key = [7u8; super::DIGEST_SIZE];
+        let data = b"blake3 keyed test";
+        let ours = super::blake3_keyed(&key, data);
+        let expected = blake3::Hasher::new_keyed(&key).update(data).finalize();
+
+        assert_eq!(ours.as_bytes(), expected.as_bytes());
+    }
+
+    #[test]
+    fn sha256_with_context_prefixes_the_context() {
+        let mut prefixed = 3u64.to_le_bytes().to_vec();
+
+        prefixed.extend_from_slice(b"ctxdata");
+
+        let expected: [u8; super::DIGEST_SIZE] = Sha256::digest(&prefixed).into();
+
+        assert_eq!(super::sha256_with_context("ctx", b"data"), expected);
+    }
+
+    #[test]
+    fn blake3_with_context_matches_derive_key() {
+        let ours = super::blake3_with_context("blake3 context", b"material");
+
+        assert_eq!(
+            ours.as_bytes(),
+            &blake3::derive_key("blake3 context", b"material")
+        );
+    }
 }
@@ -0,0 +1,109 @@
+//! The lowercase hexadecimal representation.
+
+use crate::{HASH_SIZE_BIN, HASH_SIZE_HEX};
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes the internal representation as lowercase hexadecimal.
+#[inline]
+#[must_use]
+pub const fn encode(inner: &[u8; HASH_SIZE_BIN]) -> [u8; HASH_SIZE_HEX] {
+    let mut encoded = [0u8; HASH_SIZE_HEX];
+    let mut index = 0;
+
+    while index < HASH_SIZE_BIN {
+        encoded[2 * index] = DIGITS[(inner[index] >> 4) as usize];
+        encoded[2 * index + 1] = DIGITS[(inner[index] & 0xF) as usize];
+        index += 1;
+    }
+
+    encoded
+}
+
+/// Decodes a hexadecimal representation.
+///
+/// Decoding is lenient: it is case-insensitive, it skips any byte that is not
+/// a hexadecimal digit, which shifts every subsequent digit, and it
+/// zero-fills when `bytes` is shorter than a whole hash. Truncation is left
+/// for the Reed-Solomon codec to correct.
+#[must_use]
+pub fn decode(bytes: &[u8]) -> [u8; HASH_SIZE_BIN] {
+    let mut inner = [0u8; HASH_SIZE_BIN];
+
+    let digits = bytes.iter().filter_map(|&byte| digit(byte));
+
+    for (index, digit) in digits.take(2 * HASH_SIZE_BIN).enumerate() {
+        inner[index / 2] |= digit << (4 * (1 - index % 2));
+    }
+
+    inner
+}
+
+/// Returns the number of output bytes of [`decode`] that `bytes` fully
+/// determines.
+///
+/// Only hexadecimal digits contribute bits, and a trailing lone digit does
+/// not count the byte it half fills.
+#[inline]
+#[must_use]
+pub fn decoded_len(bytes: &[u8]) -> usize {
+    let digits = bytes.iter().filter(|byte| byte.is_ascii_hexdigit()).count();
+
+    (digits / 2).min(HASH_SIZE_BIN)
+}
+
+const fn digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{decode, decoded_len, encode};
+    use crate::{Hash, HASH_SIZE_BIN, HASH_SIZE_HEX};
+
+    #[test]
+    fn encode_produces_lowercase_digits() {
+        let hash = Hash::hash(b"hex").expect("hash should work");
+        let encoded = encode(&hash.inner);
+
+        assert_eq!(encoded.len(), HASH_SIZE_HEX);
+        assert!(encoded
+            .iter()
+            .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(byte)));
+        assert_eq!(&encoded[..2], format!("{:02x}", hash.inner[0]).as_bytes());
+    }
+
+    #[test]
+    fn decode_is_case_insensitive_and_skips_separators() {
+        let hash = Hash::hash(b"lenient").expect("hash should work");
+        let encoded = encode(&hash.inner);
+        let upper = encoded.to_ascii_uppercase();
+        let spaced: Vec<u8> = encoded
+            .chunks(8)
+            .flat_map(|chunk| chunk.iter().copied().chain([b' ']))
+            .collect();
+
+        assert_eq!(decode(&upper), hash.inner);
+        assert_eq!(decode(&spaced), hash.inner);
+        assert_eq!(decoded_len(&spaced), HASH_SIZE_BIN);
+    }
+
+    #[test]
+    fn decoded_len_ignores_a_trailing_lone_digit() {
+        let encoded = encode(&[0xAB; HASH_SIZE_BIN]);
+
+        assert_eq!(decoded_len(&encoded[..85]), 42);
+
+        let decoded = decode(&encoded[..85]);
+
+        assert_eq!(decoded[41], 0xAB);
+        assert_eq!(decoded[42], 0xA0);
+        assert_eq!(decoded[43], 0);
+    }
+}
@@ -1,23 +1,26 @@
-//! The two textual representations of a [`Hash`](crate::Hash).
+//! The textual representations of a [`Hash`](crate::Hash).
 //!
-//! Both encode the same [`HASH_SIZE_BIN`](crate::HASH_SIZE_BIN)-byte internal
+//! All encode the same [`HASH_SIZE_BIN`](crate::HASH_SIZE_BIN)-byte internal
 //! representation and differ only in alphabet:
 //!
 //! - [`crockford`] is the canonical form: case-insensitive, and free of the
 //!   ambiguous glyphs `I`, `L`, `O`, and `U`.
 //! - [`base64`] is the compact form, using the URL-safe alphabet.
+//! - [`hex`] is lowercase hexadecimal, for systems that understand nothing
+//!   else.
 //!
 //! Their encoded lengths differ, which is what lets
 //! [`Hash::validate`](crate::Hash::validate) tell them apart by length alone.
 
 pub mod base64;
 pub mod crockford;
+pub mod hex;
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
-    use super::{base64, crockford};
-    use crate::{hash_inner, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_CROCKFORD};
+    use super::{base64, crockford, hex};
+    use crate::{hash_inner, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_CROCKFORD, HASH_SIZE_HEX};
 
     #[test]
     fn crockford_round_trips() {
@@ -39,9 +42,20 @@ mod tests {
         assert_eq!(base64::decode(&encoded), inner);
     }
 
+    #[test]
+    fn hex_round_trips() {
+        let inner = hash_inner(b"hex round trip").expect("hash_inner should work");
+
+        let encoded = hex::encode(&inner);
+
+        assert_eq!(encoded.len(), HASH_SIZE_HEX);
+        assert_eq!(hex::decode(&encoded), inner);
+    }
+
     #[test]
     fn representations_differ_in_length() {
         assert_ne!(HASH_SIZE_CROCKFORD, HASH_SIZE_BASE64);
+        assert_ne!(HASH_SIZE_HEX, HASH_SIZE_CROCKFORD);
     }
 
     #[test]
@@ -51,6 +65,7 @@ mod tests {
 
             assert_eq!(crockford::decode(&crockford::encode(&inner)), inner);
             assert_eq!(base64::decode(&base64::encode(&inner)), inner);
+            assert_eq!(hex::decode(&hex::encode(&inner)), inner);
         }
     }
 }
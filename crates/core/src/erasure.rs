@@ -0,0 +1,386 @@
+//! Errors-and-erasures decoding of the internal representation.
+//!
+//! [`ps_ecc`] corrects unknown errors only, each of which costs two parity
+//! bytes. An erasure, a byte whose position is known to be bad, costs one, so
+//! the [`PARITY_SIZE`]-byte parity block recovers any combination of `v`
+//! errors and `e` erasures with `2 * v + e <= PARITY_SIZE`.
+//!
+//! The codec mirrors [`ps_ecc`]: GF(256) under the primitive polynomial
+//! `0x11D`, generator roots `α^1..=α^PARITY_SIZE`, and the codeword laid out
+//! as the parity block followed by the data, lowest degree first. Syndromes
+//! and polynomial evaluation come from [`ps_ecc`] itself; it keeps its scalar
+//! field arithmetic private and its [`ps_ecc::euclidean`] solves the
+//! errors-only key equation, so the erasure locator and Berlekamp-Massey
+//! below bring their own multiplication tables.
+
+use ps_ecc::{Polynomial, RSComputeErrorsError, RSDecodeError, ReedSolomon};
+
+use crate::{HASH_SIZE_BIN, PARITY_OFFSET, PARITY_SIZE};
+
+const PRIMITIVE_POLY: u16 = 0x11D;
+
+/// `EXP[i] = α^i`, doubled in length so that sums of two logarithms index it
+/// directly.
+const EXP: [u8; 512] = {
+    let mut exp = [0u8; 512];
+    let mut current = 1u16;
+    let mut i = 0;
+
+    while i < 512 {
+        exp[i] = current as u8;
+        current <<= 1;
+
+        if current & 0x100 != 0 {
+            current ^= PRIMITIVE_POLY;
+        }
+
+        i += 1;
+    }
+
+    exp
+};
+
+/// `LOG[α^i] = i`; `LOG[0]` is unused.
+const LOG: [u8; 256] = {
+    let mut log = [0u8; 256];
+    let mut i = 0;
+
+    while i < 255 {
+        log[EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    log
+};
+
+fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    EXP[usize::from(LOG[usize::from(a)]) + usize::from(LOG[usize::from(b)])]
+}
+
+/// Divides `a` by the nonzero `b`.
+fn div(a: u8, b: u8) -> u8 {
+    if a == 0 {
+        return 0;
+    }
+
+    EXP[usize::from(LOG[usize::from(a)]) + 255 - usize::from(LOG[usize::from(b)])]
+}
+
+/// Returns `α^power`.
+const fn alpha(power: usize) -> u8 {
+    EXP[power % 255]
+}
+
+fn too_many_errors() -> RSDecodeError {
+    RSComputeErrorsError::TooManyErrors.into()
+}
+
+/// Maps an offset in the internal representation to its codeword position.
+const fn codeword_position(offset: usize) -> usize {
+    if offset < PARITY_OFFSET {
+        offset + PARITY_SIZE
+    } else {
+        offset - PARITY_OFFSET
+    }
+}
+
+/// Maps a codeword position to its offset in the internal representation.
+const fn inner_offset(position: usize) -> usize {
+    if position < PARITY_SIZE {
+        position + PARITY_OFFSET
+    } else {
+        position - PARITY_SIZE
+    }
+}
+
+/// Corrects `inner` in place, treating the bytes at `erasures`, which are
+/// distinct offsets into the internal representation, as known to be bad.
+///
+/// # Errors
+///
+/// - [`RSDecodeError`] if the errors and erasures exceed what the parity
+///   block can correct.
+pub fn correct_with_erasures(
+    inner: &mut [u8; HASH_SIZE_BIN],
+    erasures: &[usize],
+) -> Result<(), RSDecodeError> {
+    let syndromes =
+        ReedSolomon::compute_syndromes_detached(&inner[PARITY_OFFSET..], &inner[..PARITY_OFFSET])?;
+
+    if syndromes.is_zero() {
+        return Ok(());
+    }
+
+    let syndromes = syndromes.first_n_coefficients(PARITY_SIZE);
+
+    if erasures.len() > PARITY_SIZE {
+        return Err(too_many_errors());
+    }
+
+    // The erasure locator, whose roots are the inverses of the erasure
+    // locators α^position.
+    let mut gamma = vec![1u8];
+
+    for &offset in erasures {
+        let locator = alpha(codeword_position(offset));
+
+        gamma.push(0);
+
+        for i in (1..gamma.len()).rev() {
+            gamma[i] ^= mul(gamma[i - 1], locator);
+        }
+    }
+
+    let locator = berlekamp_massey(syndromes, gamma, erasures.len())?;
+    let degree = locator.len() - 1;
+
+    let positions: Vec<usize> = (0..HASH_SIZE_BIN)
+        .filter(|&position| Polynomial::eval_coefficients_at(&locator, alpha(255 - position)) == 0)
+        .collect();
+
+    if positions.len() != degree {
+        return Err(too_many_errors());
+    }
+
+    let mut evaluator = vec![0u8; PARITY_SIZE];
+
+    for (i, &syndrome) in syndromes.iter().enumerate() {
+        for (j, &coefficient) in locator.iter().enumerate().take(PARITY_SIZE - i) {
+            evaluator[i + j] ^= mul(syndrome, coefficient);
+        }
+    }
+
+    for position in positions {
+        let x_inverse = alpha(255 - position);
+        let denominator = Polynomial::eval_coefficients_derivative_at(&locator, x_inverse);
+
+        if denominator == 0 {
+            return Err(too_many_errors());
+        }
+
+        inner[inner_offset(position)] ^= div(
+            Polynomial::eval_coefficients_at(&evaluator, x_inverse),
+            denominator,
+        );
+    }
+
+    // Past the budget, the locator can still have the right number of roots
+    // and yield a word that is no codeword at all.
+    if !ReedSolomon::compute_syndromes_detached(&inner[PARITY_OFFSET..], &inner[..PARITY_OFFSET])?
+        .is_zero()
+    {
+        return Err(too_many_errors());
+    }
+
+    Ok(())
+}
+
+/// Finds the errata locator from the syndromes, starting from the erasure
+/// locator `gamma` of `erasures` erasures.
+fn berlekamp_massey(
+    syndromes: &[u8],
+    gamma: Vec<u8>,
+    erasures: usize,
+) -> Result<Vec<u8>, RSDecodeError> {
+    let mut current = gamma.clone();
+    let mut previous = gamma;
+    let mut length = erasures;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1u8;
+
+    for step in erasures..syndromes.len() {
+        let mut discrepancy = syndromes[step];
+
+        for (i, &coefficient) in current.iter().enumerate().skip(1).take(step) {
+            discrepancy ^= mul(coefficient, syndromes[step - i]);
+        }
+
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        let scale = div(discrepancy, previous_discrepancy);
+        let mut next = current.clone();
+
+        next.resize(next.len().max(previous.len() + shift), 0);
+
+        for (i, &coefficient) in previous.iter().enumerate() {
+            next[i + shift] ^= mul(scale, coefficient);
+        }
+
+        if 2 * length <= step + erasures {
+            length = step + 1 + erasures - length;
+            previous = std::mem::replace(&mut current, next);
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            current = next;
+            shift += 1;
+        }
+    }
+
+    while current.len() > 1 && current.last() == Some(&0) {
+        current.pop();
+    }
+
+    if current.len() - 1 != length || 2 * length > syndromes.len() + erasures {
+        return Err(too_many_errors());
+    }
+
+    Ok(current)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use ps_ecc::ReedSolomon;
+
+    use super::{alpha, codeword_position, correct_with_erasures, mul};
+    use crate::{hash_inner, HASH_SIZE_BIN, PARITY_OFFSET, PARITY_SIZE};
+
+    /// Picks `count` distinct offsets, deterministically from `seed`.
+    fn offsets(seed: u64, count: usize) -> Vec<usize> {
+        let mut state = seed;
+        let mut chosen = Vec::new();
+
+        while chosen.len() < count {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            let offset = (state >> 33) as usize % HASH_SIZE_BIN;
+
+            if !chosen.contains(&offset) {
+                chosen.push(offset);
+            }
+        }
+
+        chosen
+    }
+
+    fn damage(inner: &mut [u8; HASH_SIZE_BIN], offsets: &[usize]) {
+        for &offset in offsets {
+            inner[offset] ^= 0xA5;
+        }
+    }
+
+    #[test]
+    fn field_arithmetic_agrees_with_ps_ecc() {
+        // Syndromes computed with this module's tables, from the codeword
+        // positions it assigns to offsets, must match ps_ecc's for any
+        // received word, not only for valid codewords.
+        for seed in 0..50u64 {
+            let mut received = hash_inner(&seed.to_le_bytes()).expect("hash_inner should work");
+
+            damage(
+                &mut received,
+                &offsets(seed, usize::try_from(seed).unwrap_or(0) % 20),
+            );
+
+            let expected = ReedSolomon::compute_syndromes_detached(
+                &received[PARITY_OFFSET..],
+                &received[..PARITY_OFFSET],
+            )
+            .expect("computing syndromes should succeed");
+
+            for power in 1..=PARITY_SIZE {
+                let syndrome = (0..HASH_SIZE_BIN).fold(0, |acc, offset| {
+                    acc ^ mul(received[offset], alpha(power * codeword_position(offset)))
+                });
+
+                assert_eq!(
+                    syndrome,
+                    expected.first_n_coefficients(PARITY_SIZE)[power - 1],
+                    "seed {seed}, syndrome {power}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn corrects_every_mix_within_the_budget() {
+        let original = hash_inner(b"errata").expect("hash_inner should work");
+
+        for erasures in 0..=PARITY_SIZE {
+            for errors in 0..=(PARITY_SIZE - erasures) / 2 {
+                for seed in 0..20 {
+                    let chosen = offsets(seed * 31 + 7, erasures + errors);
+                    let mut received = original;
+
+                    damage(&mut received, &chosen);
+
+                    correct_with_erasures(&mut received, &chosen[..erasures])
+                        .expect("correction within the budget should succeed");
+
+                    assert_eq!(
+                        received, original,
+                        "{erasures} erasures, {errors} errors, seed {seed}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_mixes_just_over_the_budget() {
+        let original = hash_inner(b"over budget").expect("hash_inner should work");
+
+        // With more erasures, too few syndromes are left over to tell an
+        // overflow from a nearby codeword, which then is a correct decoding.
+        for erasures in 0..=6 {
+            let errors = (PARITY_SIZE - erasures) / 2 + 1;
+
+            for seed in 0..50 {
+                let chosen = offsets(seed * 17 + 3, erasures + errors);
+                let mut received = original;
+
+                damage(&mut received, &chosen);
+
+                assert!(
+                    correct_with_erasures(&mut received, &chosen[..erasures]).is_err(),
+                    "{erasures} erasures, {errors} errors, seed {seed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tolerates_erasures_that_hold_correct_bytes() {
+        let original = hash_inner(b"intact erasures").expect("hash_inner should work");
+        let mut received = original;
+
+        damage(&mut received, &[5]);
+
+        correct_with_erasures(&mut received, &[5, 6, 7, 40])
+            .expect("correction within the budget should succeed");
+
+        assert_eq!(received, original);
+    }
+
+    #[test]
+    fn leaves_a_valid_codeword_untouched() {
+        let original = hash_inner(b"untouched").expect("hash_inner should work");
+        let mut received = original;
+
+        correct_with_erasures(&mut received, &[0, 1, 2])
+            .expect("a valid codeword should need no correction");
+
+        assert_eq!(received, original);
+    }
+
+    #[test]
+    fn rejects_more_erasures_than_parity_bytes() {
+        let original = hash_inner(b"too many").expect("hash_inner should work");
+        let chosen = offsets(1, PARITY_SIZE + 1);
+        let mut received = original;
+
+        damage(&mut received, &chosen);
+
+        assert!(correct_with_erasures(&mut received, &chosen).is_err());
+    }
+}
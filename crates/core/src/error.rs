@@ -1,6 +1,7 @@
 #![allow(clippy::module_name_repetitions)]
 
 use ps_ecc::{RSDecodeError, RSGenerateParityError};
+use ps_pint16::PackedInt;
 use thiserror::Error;
 
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
@@ -12,13 +13,99 @@ pub enum HashError {
     ZeroDigest,
 }
 
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum HashReadError {
+    #[error(transparent)]
+    HashError(#[from] HashError),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum HashValidationError {
+    #[error("Erasure position {0} is outside the input")]
+    ErasureOutOfRange(usize),
     #[error("Invalid Hash length: {0}")]
     InvalidLength(usize),
+    #[error("The hash is not in canonical form")]
+    NonCanonical,
     #[error(transparent)]
     RSDecodeError(#[from] RSDecodeError),
     #[error("The digest is all zeros")]
     ZeroDigest,
 }
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdentifierError {
+    #[error(transparent)]
+    InvalidHash(#[from] HashValidationError),
+    #[error("The identifier is malformed")]
+    InvalidSyntax,
+    #[error("Algorithm {0:?} is not ps-hash")]
+    UnsupportedAlgorithm(String),
+    #[error("URN namespace {0:?} is not ps-hash")]
+    UnsupportedNamespace(String),
+    #[error("Scheme {0:?} is not supported")]
+    UnsupportedScheme(String),
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MerkleError {
+    #[error("A Merkle tree needs at least one leaf")]
+    EmptyTree,
+    #[error(transparent)]
+    HashError(#[from] HashError),
+    #[error("Leaf {index} is out of range for a tree of {leaf_count} leaves")]
+    IndexOutOfRange { index: u64, leaf_count: u64 },
+    #[error("The proof encoding is malformed")]
+    InvalidEncoding,
+    #[error(transparent)]
+    InvalidHash(#[from] HashValidationError),
+    #[error("The proof has {actual} siblings, but its position needs {expected}")]
+    InvalidPathLength { expected: usize, actual: usize },
+    #[error("The proof does not lead to the root")]
+    RootMismatch,
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MultiformatError {
+    #[error("The multihash digest length {0} is not that of a hash")]
+    InvalidDigestLength(u64),
+    #[error("The encoding is malformed")]
+    InvalidEncoding,
+    #[error(transparent)]
+    InvalidHash(#[from] HashValidationError),
+    #[error("A varint is malformed or not minimally encoded")]
+    InvalidVarint,
+    #[error("CID version {0} is not supported")]
+    UnsupportedCidVersion(u64),
+    #[error("Multihash code {0:#x} is not that of ps-hash")]
+    UnsupportedCode(u64),
+    #[error("Multibase prefix {0:?} is not supported")]
+    UnsupportedMultibase(char),
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PrefixError {
+    #[error("The prefix matches {0} hashes")]
+    Ambiguous(usize),
+    #[error("No hash matches the prefix")]
+    NotFound,
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    #[error("The data does not match the digest")]
+    DigestMismatch,
+    #[error(transparent)]
+    HashError(#[from] HashError),
+    #[error("The data length {actual} does not match the hashed length {}", expected.to_usize())]
+    LengthMismatch { expected: PackedInt, actual: usize },
+}
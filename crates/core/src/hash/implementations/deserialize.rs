@@ -0,0 +1,109 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::serde::HashVisitor;
+
+use super::super::Hash;
+
+impl<'de> Deserialize<'de> for Hash {
+    /// Deserializes any representation [`Hash::validate`] accepts, repairing
+    /// it where necessary.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HashVisitor::lenient())
+        } else {
+            deserializer.deserialize_bytes(HashVisitor::lenient())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::Hash;
+
+    #[test]
+    fn round_trips_through_human_readable_formats() {
+        let hash = Hash::hash(b"json round trip").expect("hashing should succeed");
+        let json = serde_json::to_string(&hash).expect("serialization should succeed");
+
+        assert_eq!(
+            serde_json::from_str::<Hash>(&json).expect("deserialization should succeed"),
+            hash
+        );
+    }
+
+    #[test]
+    fn round_trips_through_binary_formats() {
+        let hash = Hash::hash(b"bincode round trip").expect("hashing should succeed");
+        let encoded = bincode::serialize(&hash).expect("serialization should succeed");
+
+        assert_eq!(
+            bincode::deserialize::<Hash>(&encoded).expect("deserialization should succeed"),
+            hash
+        );
+    }
+
+    #[test]
+    fn repairs_like_validate() {
+        let hash = Hash::hash(b"repair").expect("hashing should succeed");
+        let json = format!("\"{}\"", &hash.to_base64()[..60]);
+
+        assert_eq!(
+            serde_json::from_str::<Hash>(&json).expect("deserialization should succeed"),
+            hash
+        );
+    }
+
+    #[test]
+    fn accepts_a_sequence_of_bytes() {
+        let hash = Hash::hash(b"sequence").expect("hashing should succeed");
+        let json = serde_json::to_string(hash.compact()).expect("serialization should succeed");
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+
+        assert_eq!(
+            serde::Deserializer::deserialize_bytes(
+                &mut deserializer,
+                crate::serde::HashVisitor::lenient()
+            )
+            .expect("deserialization should succeed"),
+            hash
+        );
+    }
+
+    #[test]
+    fn accepts_hex_as_a_sequence_of_bytes() {
+        let hash = Hash::hash(b"hex sequence").expect("hashing should succeed");
+        let json =
+            serde_json::to_string(hash.to_hex().as_bytes()).expect("serialization should succeed");
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+
+        assert_eq!(
+            serde::Deserializer::deserialize_bytes(
+                &mut deserializer,
+                crate::serde::HashVisitor::lenient()
+            )
+            .expect("deserialization should succeed"),
+            hash
+        );
+
+        let json = serde_json::to_string(&vec![b'0'; crate::HASH_SIZE_HEX + 1])
+            .expect("serialization should succeed");
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+
+        assert!(serde::Deserializer::deserialize_bytes(
+            &mut deserializer,
+            crate::serde::HashVisitor::lenient()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let error = serde_json::from_str::<Hash>("\"short\"")
+            .expect_err("an invalid hash should not deserialize");
+
+        assert!(error.to_string().contains("Invalid Hash length: 5"));
+    }
+}
@@ -1,17 +1,39 @@
-use std::fmt::{Debug, Display};
+use std::fmt::{Debug, Display, LowerHex, UpperHex};
 
 use super::super::Hash;
 
 impl Display for Hash {
-    /// Writes the canonical Crockford Base32 representation.
+    /// Writes the canonical Crockford Base32 representation, or with the
+    /// alternate flag (`{:#}`), the base64url representation.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return f.write_str(&self.to_base64());
+        }
+
         ps_crockford32::encode_into(&self.inner, f)
     }
 }
 
 impl Debug for Hash {
+    /// Writes the canonical Crockford Base32 representation.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        ps_crockford32::encode_into(&self.inner, f)
+    }
+}
+
+impl LowerHex for Hash {
+    /// Writes the lowercase hexadecimal representation, honouring the flags
+    /// as integers do: `{:#x}` prefixes `0x`, and width, fill and `0` pad.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(true, "0x", &self.to_hex())
+    }
+}
+
+impl UpperHex for Hash {
+    /// Writes the uppercase hexadecimal representation, honouring the flags
+    /// as integers do: `{:#X}` prefixes `0x`, and width, fill and `0` pad.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Display::fmt(self, f)
+        f.pad_integral(true, "0x", &self.to_hex().to_ascii_uppercase())
     }
 }
 
@@ -90,6 +112,39 @@ mod tests {
         assert_eq!(s1, s2);
     }
 
+    #[test]
+    fn alternate_display_is_base64() {
+        let h = Hash::hash(b"alternate").expect("hashing should succeed");
+
+        assert_eq!(format!("{h:#}"), h.to_base64());
+        assert_eq!(format!("{h:#?}"), h.to_crockford());
+    }
+
+    #[test]
+    fn hex_formatting_matches_to_hex() {
+        let h = Hash::hash(b"hex").expect("hashing should succeed");
+
+        assert_eq!(format!("{h:x}"), h.to_hex());
+        assert_eq!(format!("{h:X}"), h.to_hex().to_uppercase());
+        assert_eq!(
+            Hash::validate(format!("{h:X}")).expect("validation should succeed"),
+            h
+        );
+    }
+
+    #[test]
+    fn hex_formatting_honours_the_flags() {
+        let h = Hash::hash(b"hex flags").expect("hashing should succeed");
+        let hex = h.to_hex();
+
+        assert_eq!(format!("{h:#x}"), format!("0x{hex}"));
+        assert_eq!(format!("{h:#X}"), format!("0x{}", hex.to_ascii_uppercase()));
+        assert_eq!(format!("{h:>100x}"), format!("    {hex}"));
+        assert_eq!(format!("{h:*<98x}"), format!("{hex}**"));
+        assert_eq!(format!("{h:#0100x}"), format!("0x00{hex}"));
+        assert_eq!(format!("{h:10x}"), hex);
+    }
+
     #[test]
     fn display_round_trips() {
         let original = Hash::hash(b"round trip").expect("hashing should succeed");
@@ -1,6 +1,6 @@
 use crate::{
-    encoding::{base64, crockford},
-    HASH_SIZE_BASE64, HASH_SIZE_CROCKFORD,
+    encoding::{base64, crockford, hex},
+    HASH_SIZE_BASE64, HASH_SIZE_CROCKFORD, HASH_SIZE_HEX,
 };
 
 use super::super::Hash;
@@ -17,10 +17,24 @@ impl From<Hash> for [u8; HASH_SIZE_BASE64] {
     }
 }
 
+impl From<Hash> for [u8; HASH_SIZE_HEX] {
+    fn from(hash: Hash) -> [u8; HASH_SIZE_HEX] {
+        hex::encode(&hash.inner)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
-    use crate::{Hash, HASH_SIZE_BASE64, HASH_SIZE_CROCKFORD};
+    use crate::{Hash, HASH_SIZE_BASE64, HASH_SIZE_CROCKFORD, HASH_SIZE_HEX};
+
+    #[test]
+    fn hex_array_matches_to_hex() {
+        let h = Hash::hash(b"hex").expect("hashing should succeed");
+        let hex: [u8; HASH_SIZE_HEX] = h.into();
+
+        assert_eq!(&hex[..], h.to_hex().as_bytes());
+    }
 
     #[test]
     fn both_array_sizes_are_reachable() {
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use crate::HashValidationError;
+
+use super::super::Hash;
+
+impl FromStr for Hash {
+    type Err = HashValidationError;
+
+    /// Parses any representation [`Hash::validate`] accepts, repairing it
+    /// where necessary.
+    ///
+    /// Use [`Hash::parse_canonical`] to reject input that needs repair.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::validate(s)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{Hash, HashValidationError};
+
+    #[test]
+    fn parse_crockford() {
+        let original = Hash::hash(b"parse").expect("hashing should succeed");
+        let parsed: Hash = original
+            .to_crockford()
+            .parse()
+            .expect("parsing a valid hash should succeed");
+
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn parse_base64() {
+        let original = Hash::hash(b"parse").expect("hashing should succeed");
+        let parsed: Hash = original
+            .to_base64()
+            .parse()
+            .expect("parsing a valid hash should succeed");
+
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn parse_repairs_like_validate() {
+        let original = Hash::hash(b"repair").expect("hashing should succeed");
+        let lowercase = original.to_crockford().to_lowercase();
+
+        assert_eq!(
+            lowercase.parse::<Hash>(),
+            Ok(original),
+            "parsing should accept what validate accepts"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_length() {
+        assert_eq!(
+            "short".parse::<Hash>(),
+            Err(HashValidationError::InvalidLength(5))
+        );
+    }
+}
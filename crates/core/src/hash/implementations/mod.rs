@@ -1,8 +1,13 @@
+#[cfg(feature = "serde")]
+mod deserialize;
 mod display;
 mod eq;
 mod from_array;
+mod from_str;
 mod from_string;
 mod from_vec;
 mod hash_trait;
 mod ord;
+#[cfg(feature = "serde")]
+mod serialize;
 mod try_from;
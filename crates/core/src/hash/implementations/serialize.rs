@@ -0,0 +1,41 @@
+use serde::{Serialize, Serializer};
+
+use super::super::Hash;
+
+impl Serialize for Hash {
+    /// Serializes the canonical Crockford Base32 string in human-readable
+    /// formats, and the raw bytes of the internal representation otherwise.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_crockford())
+        } else {
+            serializer.serialize_bytes(&self.inner)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{Hash, HASH_SIZE_BIN};
+
+    #[test]
+    fn serializes_crockford_to_human_readable_formats() {
+        let hash = Hash::hash(b"json").expect("hashing should succeed");
+
+        assert_eq!(
+            serde_json::to_string(&hash).expect("serialization should succeed"),
+            format!("\"{hash}\"")
+        );
+    }
+
+    #[test]
+    fn serializes_raw_bytes_to_binary_formats() {
+        let hash = Hash::hash(b"bincode").expect("hashing should succeed");
+        let encoded = bincode::serialize(&hash).expect("serialization should succeed");
+
+        // bincode prefixes byte strings with their length as a u64.
+        assert_eq!(encoded.len(), 8 + HASH_SIZE_BIN);
+        assert!(encoded.ends_with(&hash.inner));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::HASH_SIZE_BIN;
+
+use super::super::Hash;
+
+impl Hash {
+    /// Returns the internal representation: digest, length, and parity.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; HASH_SIZE_BIN] {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::Hash;
+
+    #[test]
+    fn as_bytes_is_the_internal_representation() {
+        let h = Hash::hash(b"bytes").expect("hashing should succeed");
+
+        assert_eq!(h.as_bytes(), &h.inner);
+    }
+
+    #[test]
+    fn as_bytes_round_trips_via_validate() {
+        let original = Hash::hash(b"round trip").expect("hashing should succeed");
+
+        assert_eq!(Hash::validate(original.as_bytes()), Ok(original));
+    }
+}
@@ -0,0 +1,138 @@
+use std::io::ErrorKind;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{HashReadError, Hasher};
+
+use super::{super::Hash, from_reader::READ_BUFFER_SIZE};
+
+impl Hash {
+    /// Hashes everything an async `reader` yields until end of input.
+    ///
+    /// This is the async counterpart of [`Hash::from_reader`]: the input is
+    /// streamed through a fixed-size buffer, and the result equals
+    /// [`Hash::hash`] on the same bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`HashReadError::IoError`] if reading fails. Reads interrupted by
+    ///   [`ErrorKind::Interrupted`] are retried.
+    /// - [`HashReadError::HashError`] if hashing fails.
+    pub async fn from_async_reader(
+        mut reader: impl AsyncRead + Unpin,
+    ) -> Result<Self, HashReadError> {
+        let mut hasher = Hasher::new();
+        let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+
+        loop {
+            match reader.read(&mut buffer).await {
+                Ok(0) => break,
+                Ok(read) => {
+                    hasher.update(&buffer[..read]);
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(hasher.finalize()?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::{
+        io::{self, ErrorKind},
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    use crate::{Hash, HashReadError};
+
+    /// Yields its input one byte at a time, interrupting every other read.
+    struct Stuttering<'a> {
+        data: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl AsyncRead for Stuttering<'_> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            self.interrupt = !self.interrupt;
+
+            if self.interrupt {
+                return Poll::Ready(Err(ErrorKind::Interrupted.into()));
+            }
+
+            if let Some((&first, rest)) = self.data.split_first() {
+                buf.put_slice(&[first]);
+                self.data = rest;
+            }
+
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    struct Failing;
+
+    impl AsyncRead for Failing {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            _: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            Poll::Ready(Err(io::Error::other("connection reset")))
+        }
+    }
+
+    #[tokio::test]
+    async fn from_async_reader_matches_hash() {
+        let data = b"async streamed".repeat(10_000);
+
+        assert_eq!(
+            Hash::from_async_reader(data.as_slice())
+                .await
+                .expect("reading should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+
+    #[tokio::test]
+    async fn from_async_reader_handles_empty_input() {
+        assert_eq!(
+            Hash::from_async_reader(tokio::io::empty())
+                .await
+                .expect("reading should succeed"),
+            Hash::hash([]).expect("hashing should succeed")
+        );
+    }
+
+    #[tokio::test]
+    async fn from_async_reader_retries_interrupted_reads() {
+        let reader = Stuttering {
+            data: b"interrupted",
+            interrupt: false,
+        };
+
+        assert_eq!(
+            Hash::from_async_reader(reader)
+                .await
+                .expect("reading should succeed"),
+            Hash::hash(b"interrupted").expect("hashing should succeed")
+        );
+    }
+
+    #[tokio::test]
+    async fn from_async_reader_reports_io_errors() {
+        assert!(matches!(
+            Hash::from_async_reader(Failing).await,
+            Err(HashReadError::IoError(_))
+        ));
+    }
+}
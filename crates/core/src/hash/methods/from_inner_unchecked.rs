@@ -0,0 +1,42 @@
+use crate::HASH_SIZE_BIN;
+
+use super::super::Hash;
+
+impl Hash {
+    /// Wraps the internal representation of a hash without validating it.
+    ///
+    /// This is the `const` constructor behind the `hash_value!` macro, which
+    /// computes the bytes at compile time with [`hash_inner`](crate::hash_inner).
+    /// The bytes are trusted to be such a result: nothing unsafe happens if
+    /// they are not, but the hash then fails [`Hash::validate`] and matches
+    /// no data.
+    #[must_use]
+    pub const fn from_inner_unchecked(inner: [u8; HASH_SIZE_BIN]) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{hash_inner, Hash};
+
+    const EMPTY: Hash = Hash::from_inner_unchecked([0; 48]);
+
+    #[test]
+    fn wraps_the_bytes_unchanged() {
+        let inner = hash_inner(b"trusted").expect("hash_inner should succeed");
+        let hash = Hash::from_inner_unchecked(inner);
+
+        assert_eq!(
+            hash,
+            Hash::hash(b"trusted").expect("hashing should succeed")
+        );
+        assert_eq!(hash.as_bytes(), &inner);
+    }
+
+    #[test]
+    fn is_usable_in_const_context() {
+        assert_eq!(EMPTY.as_bytes(), &[0; 48]);
+    }
+}
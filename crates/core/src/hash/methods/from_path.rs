@@ -0,0 +1,124 @@
+use std::{fs::File, path::Path};
+
+use crate::HashReadError;
+
+use super::super::Hash;
+
+/// Smallest file [`Hash::from_path`] memory-maps rather than reads.
+///
+/// Below this size, setting up the mapping costs more than it saves.
+#[cfg(feature = "mmap")]
+const MMAP_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+impl Hash {
+    /// Hashes the contents of the file at `path`.
+    ///
+    /// The file is streamed through [`Hash::from_reader`]. With the `mmap`
+    /// feature enabled, regular files of at least 16 MiB are memory-mapped and
+    /// hashed in place instead. Either way, the result equals [`Hash::hash`]
+    /// on the file contents.
+    ///
+    /// Only enable `mmap` where no other process truncates the files being
+    /// hashed: a mapped page past the new end of a file raises `SIGBUS`, which
+    /// kills the process rather than surfacing as an error.
+    ///
+    /// # Errors
+    ///
+    /// - [`HashReadError::IoError`] if opening or reading the file fails.
+    /// - [`HashReadError::HashError`] if hashing fails.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, HashReadError> {
+        let file = File::open(path)?;
+
+        #[cfg(feature = "mmap")]
+        if let Some(hash) = hash_mapped(&file)? {
+            return Ok(hash);
+        }
+
+        Self::from_reader(file)
+    }
+}
+
+/// Hashes `file` through a memory mapping, or returns `None` if it is too
+/// small or cannot be mapped.
+#[cfg(feature = "mmap")]
+fn hash_mapped(file: &File) -> Result<Option<Hash>, HashReadError> {
+    let metadata = file.metadata()?;
+
+    if !metadata.is_file() || metadata.len() < MMAP_THRESHOLD {
+        return Ok(None);
+    }
+
+    // SAFETY: the mapping is read-only and dropped before returning. It is
+    // only sound if no other process truncates the file meanwhile, which this
+    // function cannot rule out: touching a page past the new end raises
+    // `SIGBUS` and kills the process. Callers opt into that hazard through the
+    // `mmap` feature, as documented on `Hash::from_path`. A concurrent rewrite
+    // that keeps the length only makes the hash reflect some mix of old and
+    // new contents, as a concurrent `read` would.
+    let Ok(map) = (unsafe { memmap2::Mmap::map(file) }) else {
+        return Ok(None);
+    };
+
+    Ok(Some(Hash::hash(&map[..])?))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::{ErrorKind, Write};
+
+    use crate::{Hash, HashReadError};
+
+    fn temp_file_with(data: &[u8]) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().expect("creating a temp file should succeed");
+
+        file.write_all(data).expect("writing should succeed");
+        file.flush().expect("flushing should succeed");
+
+        file
+    }
+
+    #[test]
+    fn from_path_matches_hash() {
+        let data = b"file contents".repeat(10_000);
+        let file = temp_file_with(&data);
+
+        assert_eq!(
+            Hash::from_path(file.path()).expect("hashing the file should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn from_path_handles_empty_files() {
+        let file = temp_file_with(b"");
+
+        assert_eq!(
+            Hash::from_path(file.path()).expect("hashing the file should succeed"),
+            Hash::hash([]).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn from_path_reports_missing_files() {
+        let dir = tempfile::tempdir().expect("creating a temp dir should succeed");
+
+        match Hash::from_path(dir.path().join("missing")) {
+            Err(HashReadError::IoError(error)) => assert_eq!(error.kind(), ErrorKind::NotFound),
+            other => panic!("expected a NotFound error, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn from_path_matches_hash_above_the_mmap_threshold() {
+        let len = usize::try_from(super::MMAP_THRESHOLD).expect("threshold should fit") + 17;
+        let data: Vec<u8> = (0..len).map(|index| (index % 251) as u8).collect();
+        let file = temp_file_with(&data);
+
+        assert_eq!(
+            Hash::from_path(file.path()).expect("hashing the file should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+}
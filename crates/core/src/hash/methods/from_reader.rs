@@ -0,0 +1,120 @@
+use std::io::{ErrorKind, Read};
+
+use crate::{HashReadError, Hasher};
+
+use super::super::Hash;
+
+/// Size of the buffer [`Hash::from_reader`] streams through.
+pub(super) const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+impl Hash {
+    /// Hashes everything `reader` yields until end of input.
+    ///
+    /// The input is streamed through a fixed-size buffer, so memory use does
+    /// not grow with its length. The result equals [`Hash::hash`] on the same
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`HashReadError::IoError`] if reading fails. Reads interrupted by
+    ///   [`ErrorKind::Interrupted`] are retried.
+    /// - [`HashReadError::HashError`] if hashing fails.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, HashReadError> {
+        let mut hasher = Hasher::new();
+        let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => {
+                    hasher.update(&buffer[..read]);
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(hasher.finalize()?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::{self, ErrorKind, Read};
+
+    use super::READ_BUFFER_SIZE;
+    use crate::{Hash, HashReadError};
+
+    /// Yields its input one byte at a time, interrupting every other read.
+    struct Stuttering<'a> {
+        data: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl Read for Stuttering<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+
+            if self.interrupt {
+                return Err(ErrorKind::Interrupted.into());
+            }
+
+            let Some((&first, rest)) = self.data.split_first() else {
+                return Ok(0);
+            };
+
+            buf[0] = first;
+            self.data = rest;
+
+            Ok(1)
+        }
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("broken pipe"))
+        }
+    }
+
+    #[test]
+    fn from_reader_matches_hash() {
+        let data = b"streamed".repeat(3 * READ_BUFFER_SIZE / 8 + 5);
+
+        assert_eq!(
+            Hash::from_reader(data.as_slice()).expect("reading should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn from_reader_handles_empty_input() {
+        assert_eq!(
+            Hash::from_reader(io::empty()).expect("reading should succeed"),
+            Hash::hash([]).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn from_reader_retries_interrupted_reads() {
+        let reader = Stuttering {
+            data: b"interrupted",
+            interrupt: false,
+        };
+
+        assert_eq!(
+            Hash::from_reader(reader).expect("reading should succeed"),
+            Hash::hash(b"interrupted").expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn from_reader_reports_io_errors() {
+        assert!(matches!(
+            Hash::from_reader(Failing),
+            Err(HashReadError::IoError(_))
+        ));
+    }
+}
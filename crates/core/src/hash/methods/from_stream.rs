@@ -0,0 +1,59 @@
+use std::{future::poll_fn, pin::Pin};
+
+use futures_core::Stream;
+
+use crate::{HashError, Hasher};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Hashes the concatenation of every chunk `stream` yields.
+    ///
+    /// Chunks may be any byte container, such as `Bytes` or `Vec<u8>`. The
+    /// result equals [`Hash::hash`] on the concatenated chunks.
+    ///
+    /// # Errors
+    ///
+    /// - [`HashError`] if hashing fails.
+    pub async fn from_stream<S>(mut stream: S) -> Result<Self, HashError>
+    where
+        S: Stream + Unpin,
+        S::Item: AsRef<[u8]>,
+    {
+        let mut hasher = Hasher::new();
+
+        while let Some(chunk) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+            hasher.update(chunk.as_ref());
+        }
+
+        hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::Hash;
+
+    #[tokio::test]
+    async fn from_stream_matches_hash_of_concatenation() {
+        let chunks = vec![b"stream".to_vec(), Vec::new(), b"ed chunks".to_vec()];
+
+        assert_eq!(
+            Hash::from_stream(tokio_stream::iter(chunks))
+                .await
+                .expect("hashing should succeed"),
+            Hash::hash(b"streamed chunks").expect("hashing should succeed")
+        );
+    }
+
+    #[tokio::test]
+    async fn from_stream_handles_empty_stream() {
+        assert_eq!(
+            Hash::from_stream(tokio_stream::empty::<&[u8]>())
+                .await
+                .expect("hashing should succeed"),
+            Hash::hash([]).expect("hashing should succeed")
+        );
+    }
+}
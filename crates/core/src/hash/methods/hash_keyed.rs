@@ -0,0 +1,97 @@
+use crate::{hash_inner_keyed, HashError, DIGEST_SIZE};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Hashes `data` under a secret `key`.
+    ///
+    /// The digest combines BLAKE3 in keyed mode with HMAC-SHA-256, both keyed
+    /// with `key`, instead of the plain SHA-256 and BLAKE3 digests used by
+    /// [`Hash::hash`]. The result has the same layout, length field and
+    /// parity as any other hash, so every encoding, [`Hash::validate`] and
+    /// [`Hash::from_parts`] work on it unchanged.
+    ///
+    /// Without the key, nobody can compute the hash of a guessed input and
+    /// so confirm that it is stored, and since both digests are pseudorandom
+    /// functions of the key, the key cannot be recovered from any number of
+    /// hashes. The length field is not keyed, so the data length is still
+    /// revealed, as with [`Hash::hash`].
+    ///
+    /// A keyed hash only equals itself under the same key, so
+    /// [`Hash::verify`] does not accept it; compare against a fresh
+    /// [`Hash::hash_keyed`] instead.
+    ///
+    /// # Errors
+    ///
+    /// Fails only if parity generation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps_hash_core::Hash;
+    ///
+    /// let key = [42u8; 32];
+    /// let keyed = Hash::hash_keyed(&key, b"private")?;
+    ///
+    /// assert_ne!(keyed, Hash::hash(b"private")?);
+    /// assert_eq!(keyed, Hash::hash_keyed(&key, b"private")?);
+    /// assert_eq!(Hash::validate(keyed.to_string())?, keyed);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn hash_keyed(key: &[u8; DIGEST_SIZE], data: impl AsRef<[u8]>) -> Result<Self, HashError> {
+        let inner = hash_inner_keyed(key, data.as_ref())?;
+
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use ps_pint16::PackedInt;
+
+    use crate::{Hash, DIGEST_SIZE};
+
+    const KEY: [u8; DIGEST_SIZE] = [0x5A; DIGEST_SIZE];
+
+    fn keyed(key: &[u8; DIGEST_SIZE], data: &[u8]) -> Hash {
+        Hash::hash_keyed(key, data).expect("hashing should succeed")
+    }
+
+    #[test]
+    fn differs_from_the_unkeyed_hash_and_between_keys() {
+        let plain = Hash::hash(b"content").expect("hashing should succeed");
+        let mut other = KEY;
+
+        other[31] ^= 1;
+
+        assert_ne!(keyed(&KEY, b"content"), plain);
+        assert_ne!(keyed(&KEY, b"content"), keyed(&other, b"content"));
+        assert_eq!(keyed(&KEY, b"content"), keyed(&KEY, b"content"));
+    }
+
+    #[test]
+    fn keeps_the_layout_of_a_plain_hash() {
+        let hash = keyed(&KEY, b"layout");
+
+        assert_eq!(hash.data_max_len(), PackedInt::from_usize(6));
+        assert_eq!(
+            Hash::validate(hash.to_string()).expect("validation should succeed"),
+            hash
+        );
+        assert_eq!(
+            Hash::validate(hash.to_base64()).expect("validation should succeed"),
+            hash
+        );
+        assert_eq!(
+            Hash::from_parts(hash.digest(), hash.data_max_len())
+                .expect("reconstruction should succeed"),
+            hash
+        );
+    }
+
+    #[test]
+    fn is_not_accepted_by_verify() {
+        assert!(keyed(&KEY, b"verify").verify(b"verify").is_err());
+    }
+}
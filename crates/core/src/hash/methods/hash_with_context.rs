@@ -0,0 +1,84 @@
+use crate::{hash_inner_with_context, HashError};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Hashes `data` in the domain named by `context`, so that equal bytes
+    /// hashed as different kinds of object get different hashes.
+    ///
+    /// The BLAKE3 digest is computed in key derivation mode with `context` as
+    /// its context string, and the SHA-256 digest over the length of
+    /// `context` as a little-endian `u64`, then `context`, then `data`. The
+    /// length prefix makes the SHA-256 inputs of two different contexts
+    /// differ for any data, and BLAKE3 separates key derivation from plain
+    /// hashing by its domain flags, so a context hash never coincides with
+    /// [`Hash::hash`] or with a hash in another context by construction.
+    /// [`Hash::hash`] itself is unchanged.
+    ///
+    /// As with BLAKE3, `context` should be a hardcoded, globally unique
+    /// string, such as `"example.com 2025-01-01 manifest"`, and never derived
+    /// from the data. The result has the layout of any other hash, and its
+    /// length field is that of `data`.
+    ///
+    /// # Errors
+    ///
+    /// Fails only if parity generation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps_hash_core::Hash;
+    ///
+    /// let bytes = b"same bytes";
+    /// let blob = Hash::hash_with_context("ps-hash example blob", bytes)?;
+    /// let manifest = Hash::hash_with_context("ps-hash example manifest", bytes)?;
+    ///
+    /// assert_ne!(blob, manifest);
+    /// assert_ne!(blob, Hash::hash(bytes)?);
+    /// # Ok::<(), ps_hash_core::HashError>(())
+    /// ```
+    pub fn hash_with_context(context: &str, data: impl AsRef<[u8]>) -> Result<Self, HashError> {
+        let inner = hash_inner_with_context(context, data.as_ref())?;
+
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use ps_pint16::PackedInt;
+
+    use crate::Hash;
+
+    fn contextual(context: &str, data: &[u8]) -> Hash {
+        Hash::hash_with_context(context, data).expect("hashing should succeed")
+    }
+
+    #[test]
+    fn separates_contexts() {
+        assert_ne!(contextual("blob", b"data"), contextual("manifest", b"data"));
+        assert_ne!(
+            contextual("blob", b"data"),
+            Hash::hash(b"data").expect("hashing should succeed")
+        );
+        assert_eq!(contextual("blob", b"data"), contextual("blob", b"data"));
+    }
+
+    #[test]
+    fn moving_bytes_between_context_and_data_changes_the_hash() {
+        assert_ne!(contextual("ab", b"c"), contextual("a", b"bc"));
+        assert_ne!(contextual("", b"data"), contextual("data", b""));
+    }
+
+    #[test]
+    fn keeps_the_layout_of_a_plain_hash() {
+        let hash = contextual("layout", b"content");
+
+        assert_eq!(hash.data_max_len(), PackedInt::from_usize(7));
+        assert_eq!(
+            Hash::validate(hash.to_string()).expect("validation should succeed"),
+            hash
+        );
+    }
+}
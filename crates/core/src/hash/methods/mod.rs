@@ -1,9 +1,25 @@
+mod as_bytes;
 mod compact;
 mod data_max_len;
 mod digest;
+#[cfg(feature = "tokio")]
+mod from_async_reader;
+mod from_inner_unchecked;
 mod from_parts;
+mod from_path;
+mod from_reader;
+#[cfg(feature = "tokio")]
+mod from_stream;
 mod hash;
+mod hash_keyed;
+mod hash_with_context;
+mod ni_uri;
 mod parity;
+mod parse_canonical;
 mod to_string;
+mod urn;
 mod validate;
 mod validate_bin_vec;
+mod validate_with_erasures;
+mod validate_with_report;
+mod verify;
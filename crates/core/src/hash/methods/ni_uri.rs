@@ -0,0 +1,159 @@
+use crate::{HashValidationError, IdentifierError, HASH_SIZE_BASE64, MIN_RECOVERABLE_BASE64};
+
+use super::super::Hash;
+
+/// The algorithm label of ps-hash in a named-information URI.
+const ALGORITHM: &str = "ps-hash";
+
+impl Hash {
+    /// Returns the hash as an RFC 6920 named-information URI with no
+    /// authority, `ni:///ps-hash;` followed by the base64url representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps_hash_core::Hash;
+    ///
+    /// let hash = Hash::hash(b"ni")?;
+    /// let uri = hash.to_ni_uri();
+    ///
+    /// assert_eq!(uri, format!("ni:///ps-hash;{}", hash.to_base64()));
+    /// assert_eq!(Hash::parse_ni_uri(&uri)?, hash);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn to_ni_uri(&self) -> String {
+        format!("ni:///{ALGORITHM};{}", self.to_base64())
+    }
+
+    /// Parses a named-information URI written by [`Hash::to_ni_uri`].
+    ///
+    /// The scheme and the algorithm label are matched case-insensitively,
+    /// and an authority, as in `ni://example.com/ps-hash;...`, is accepted
+    /// and ignored, as is a query, which RFC 6920 reserves for parameters
+    /// such as the content type. The value must be base64url, possibly
+    /// truncated as far as [`Hash::validate`] can recover; it is checked
+    /// with [`Hash::validate`], and is repaired if need be.
+    ///
+    /// # Errors
+    ///
+    /// - [`IdentifierError::UnsupportedScheme`] if `uri` is not an `ni` URI.
+    /// - [`IdentifierError::UnsupportedAlgorithm`] if the algorithm label is
+    ///   not `ps-hash`.
+    /// - [`IdentifierError::InvalidSyntax`] if the URI is not of the form
+    ///   `ni://<authority>/<algorithm>;<value>`, optionally followed by a
+    ///   query.
+    /// - [`IdentifierError::InvalidHash`] if the value is not of a base64url
+    ///   length, or the embedded hash fails validation.
+    pub fn parse_ni_uri(uri: &str) -> Result<Self, IdentifierError> {
+        let (scheme, rest) = uri.split_once(':').ok_or(IdentifierError::InvalidSyntax)?;
+
+        if !scheme.eq_ignore_ascii_case("ni") {
+            return Err(IdentifierError::UnsupportedScheme(scheme.to_owned()));
+        }
+
+        let (_authority, path) = rest
+            .strip_prefix("//")
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or(IdentifierError::InvalidSyntax)?;
+
+        let path = path.split_once('?').map_or(path, |(path, _query)| path);
+        let (algorithm, value) = path.split_once(';').ok_or(IdentifierError::InvalidSyntax)?;
+
+        if !algorithm.eq_ignore_ascii_case(ALGORITHM) {
+            return Err(IdentifierError::UnsupportedAlgorithm(algorithm.to_owned()));
+        }
+
+        if !(MIN_RECOVERABLE_BASE64..=HASH_SIZE_BASE64).contains(&value.len()) {
+            return Err(HashValidationError::InvalidLength(value.len()).into());
+        }
+
+        Ok(Self::validate(value)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{Hash, HashValidationError, IdentifierError};
+
+    fn hash() -> Hash {
+        Hash::hash(b"ni").expect("hashing should succeed")
+    }
+
+    #[test]
+    fn to_ni_uri_embeds_the_base64_representation() {
+        let hash = hash();
+        let uri = hash.to_ni_uri();
+
+        assert_eq!(&uri[..14], "ni:///ps-hash;");
+        assert_eq!(&uri[14..], hash.to_base64());
+    }
+
+    #[test]
+    fn parse_ni_uri_accepts_authorities_queries_and_any_case() {
+        let hash = hash();
+
+        for uri in [
+            hash.to_ni_uri(),
+            format!("NI://example.com/PS-HASH;{}", hash.to_base64()),
+            format!("ni:///ps-hash;{}", &hash.to_base64()[..60]),
+            format!("ni:///ps-hash;{}?ct=text/plain", hash.to_base64()),
+            format!("ni:///ps-hash;{}?", hash.to_base64()),
+        ] {
+            assert_eq!(Hash::parse_ni_uri(&uri), Ok(hash), "{uri}");
+        }
+    }
+
+    #[test]
+    fn parse_ni_uri_rejects_other_identifiers() {
+        let value = hash().to_base64();
+
+        assert_eq!(
+            Hash::parse_ni_uri(&format!("nih:///ps-hash;{value}")),
+            Err(IdentifierError::UnsupportedScheme("nih".to_owned()))
+        );
+        assert_eq!(
+            Hash::parse_ni_uri(&format!("ni:///sha-256;{value}")),
+            Err(IdentifierError::UnsupportedAlgorithm("sha-256".to_owned()))
+        );
+
+        for malformed in [
+            format!("ni:/ps-hash;{value}"),
+            format!("ni:///ps-hash{value}"),
+            "ni".to_owned(),
+        ] {
+            assert_eq!(
+                Hash::parse_ni_uri(&malformed),
+                Err(IdentifierError::InvalidSyntax),
+                "{malformed}"
+            );
+        }
+
+        assert_eq!(
+            Hash::parse_ni_uri("ni:///ps-hash;short"),
+            Err(IdentifierError::InvalidHash(
+                HashValidationError::InvalidLength(5)
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_ni_uri_rejects_values_that_are_not_base64() {
+        let hash = hash();
+
+        for value in [
+            hash.to_crockford(),
+            hash.to_hex(),
+            hash.to_base64()[..20].to_owned(),
+        ] {
+            assert_eq!(
+                Hash::parse_ni_uri(&format!("ni:///ps-hash;{value}")),
+                Err(IdentifierError::InvalidHash(
+                    HashValidationError::InvalidLength(value.len())
+                )),
+                "{value}"
+            );
+        }
+    }
+}
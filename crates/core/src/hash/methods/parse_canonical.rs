@@ -0,0 +1,234 @@
+use ps_ecc::{RSDecodeError, ReedSolomon};
+
+use crate::{
+    encoding::{base64, crockford, hex},
+    HashValidationError, DIGEST_SIZE, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_CROCKFORD,
+    HASH_SIZE_HEX, MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD,
+    MIN_RECOVERABLE_HEX, PARITY_OFFSET,
+};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Parses a hash that is already in canonical form, without repairing it.
+    ///
+    /// The input must be byte-for-byte what [`Hash::to_crockford`],
+    /// [`Hash::to_base64`] or [`Hash::to_hex`] produces for a valid hash:
+    /// full length, canonical alphabet and case, and free of errors for the
+    /// Reed-Solomon codec to correct. This suits interfaces that should
+    /// reject sloppy input rather than silently fix it; [`Hash::validate`] is
+    /// the lenient alternative.
+    ///
+    /// # Errors
+    ///
+    /// - [`HashValidationError::InvalidLength`] if the length matches no
+    ///   representation [`Hash::validate`] accepts.
+    /// - [`HashValidationError::NonCanonical`] if the input is not the exact
+    ///   canonical encoding of a valid hash, including binary and truncated
+    ///   input that [`Hash::validate`] would accept.
+    /// - [`HashValidationError::ZeroDigest`] if the digest is all zeros.
+    pub fn parse_canonical(bytes: impl AsRef<[u8]>) -> Result<Self, HashValidationError> {
+        let bytes = bytes.as_ref();
+
+        let hash = Self {
+            inner: match bytes.len() {
+                HASH_SIZE_CROCKFORD => {
+                    let inner = crockford::decode(bytes);
+
+                    if crockford::encode(&inner) != bytes {
+                        return Err(HashValidationError::NonCanonical);
+                    }
+
+                    inner
+                }
+                HASH_SIZE_BASE64 => {
+                    let inner = base64::decode(bytes);
+
+                    if base64::encode(&inner) != bytes {
+                        return Err(HashValidationError::NonCanonical);
+                    }
+
+                    inner
+                }
+                HASH_SIZE_HEX => {
+                    let inner = hex::decode(bytes);
+
+                    if hex::encode(&inner) != bytes {
+                        return Err(HashValidationError::NonCanonical);
+                    }
+
+                    inner
+                }
+                MIN_RECOVERABLE_BIN..=HASH_SIZE_BIN
+                | MIN_RECOVERABLE_BASE64..HASH_SIZE_BASE64
+                | MIN_RECOVERABLE_CROCKFORD..HASH_SIZE_CROCKFORD
+                | MIN_RECOVERABLE_HEX..HASH_SIZE_HEX => {
+                    return Err(HashValidationError::NonCanonical)
+                }
+                len => return Err(HashValidationError::InvalidLength(len)),
+            },
+        };
+
+        let (data, parity) = hash.inner.split_at(PARITY_OFFSET);
+
+        if ReedSolomon::validate_detached(parity, data)
+            .map_err(RSDecodeError::from)?
+            .is_some()
+        {
+            return Err(HashValidationError::NonCanonical);
+        }
+
+        if hash.inner[..DIGEST_SIZE] == [0; DIGEST_SIZE] {
+            return Err(HashValidationError::ZeroDigest);
+        }
+
+        Ok(hash)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{
+        Hash, HashValidationError, HASH_SIZE_BASE64, HASH_SIZE_CROCKFORD, MIN_RECOVERABLE_BASE64,
+        MIN_RECOVERABLE_CROCKFORD,
+    };
+
+    #[test]
+    fn parse_canonical_accepts_canonical_crockford() {
+        let original = Hash::hash(b"canonical").expect("hashing should succeed");
+
+        assert_eq!(Hash::parse_canonical(original.to_crockford()), Ok(original));
+    }
+
+    #[test]
+    fn parse_canonical_accepts_canonical_base64() {
+        let original = Hash::hash(b"canonical").expect("hashing should succeed");
+
+        assert_eq!(Hash::parse_canonical(original.to_base64()), Ok(original));
+    }
+
+    #[test]
+    fn parse_canonical_accepts_canonical_hex() {
+        let original = Hash::hash(b"canonical").expect("hashing should succeed");
+
+        assert_eq!(Hash::parse_canonical(original.to_hex()), Ok(original));
+    }
+
+    #[test]
+    fn parse_canonical_rejects_uppercase_hex() {
+        let original = Hash::hash(b"uppercase").expect("hashing should succeed");
+        let uppercase = original.to_hex().to_ascii_uppercase();
+
+        assert_eq!(Hash::validate(&uppercase), Ok(original));
+        assert_eq!(
+            Hash::parse_canonical(uppercase),
+            Err(HashValidationError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn parse_canonical_rejects_corrected_hex() {
+        let original = Hash::hash(b"corrupted hex").expect("hashing should succeed");
+        let mut corrupted = original.to_hex().into_bytes();
+
+        corrupted[10] = if corrupted[10] == b'a' { b'b' } else { b'a' };
+
+        assert_eq!(Hash::validate(&corrupted), Ok(original));
+        assert_eq!(
+            Hash::parse_canonical(&corrupted),
+            Err(HashValidationError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn parse_canonical_rejects_lowercase_crockford() {
+        let original = Hash::hash(b"lowercase").expect("hashing should succeed");
+
+        assert_eq!(
+            Hash::parse_canonical(original.to_crockford().to_lowercase()),
+            Err(HashValidationError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn parse_canonical_rejects_ambiguous_glyphs() {
+        let original = Hash::hash(b"glyphs").expect("hashing should succeed");
+        let crockford = original.to_crockford();
+        let aliased = crockford.replace('0', "O").replace('1', "L");
+
+        assert_ne!(aliased, crockford, "the test vector should contain 0 or 1");
+        assert_eq!(
+            Hash::parse_canonical(aliased),
+            Err(HashValidationError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn parse_canonical_rejects_the_standard_base64_alphabet() {
+        let original = Hash::hash(b"The quick brown fox jumps over the lazy dog")
+            .expect("hashing should succeed");
+        let base64 = original.to_base64();
+        let standard = base64.replace('-', "+").replace('_', "/");
+
+        assert_ne!(standard, base64, "the test vector should contain - or _");
+        assert_eq!(
+            Hash::parse_canonical(standard),
+            Err(HashValidationError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn parse_canonical_rejects_correctable_corruption() {
+        let original = Hash::hash(b"corrupted").expect("hashing should succeed");
+        let mut corrupted = original.to_crockford().into_bytes();
+
+        corrupted[10] = if corrupted[10] == b'A' { b'B' } else { b'A' };
+
+        assert_eq!(Hash::validate(&corrupted), Ok(original));
+        assert_eq!(
+            Hash::parse_canonical(&corrupted),
+            Err(HashValidationError::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn parse_canonical_rejects_truncated_and_binary_input() {
+        let original = Hash::hash(b"truncated").expect("hashing should succeed");
+        let crockford = original.to_crockford().into_bytes();
+        let base64 = original.to_base64().into_bytes();
+
+        for input in [
+            &crockford[..MIN_RECOVERABLE_CROCKFORD],
+            &crockford[..HASH_SIZE_CROCKFORD - 1],
+            &base64[..MIN_RECOVERABLE_BASE64],
+            &base64[..HASH_SIZE_BASE64 - 1],
+            original.compact(),
+            &original.inner,
+        ] {
+            assert!(Hash::validate(input).is_ok());
+            assert_eq!(
+                Hash::parse_canonical(input),
+                Err(HashValidationError::NonCanonical)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_canonical_rejects_invalid_lengths() {
+        for len in [0, 40, 49, 54, 65, 78] {
+            assert_eq!(
+                Hash::parse_canonical(vec![b'A'; len]),
+                Err(HashValidationError::InvalidLength(len))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_canonical_rejects_the_zero_digest() {
+        assert_eq!(
+            Hash::parse_canonical("0".repeat(HASH_SIZE_CROCKFORD)),
+            Err(HashValidationError::ZeroDigest)
+        );
+    }
+}
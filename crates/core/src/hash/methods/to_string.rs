@@ -1,3 +1,5 @@
+use crate::encoding::hex;
+
 use super::super::Hash;
 
 impl Hash {
@@ -20,19 +22,31 @@ impl Hash {
 
     /// Returns the unpadded base64url representation.
     ///
-    /// This is the shorter of the two representations, and the better choice
+    /// This is the shortest textual representation, and the better choice
     /// where a hash is only ever handled by machines.
     #[must_use]
     pub fn to_base64(&self) -> String {
         ps_base64::encode(&self.inner)
     }
+
+    /// Returns the lowercase hexadecimal representation.
+    ///
+    /// This is the longest representation, for systems that understand
+    /// nothing else.
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        hex::encode(&self.inner)
+            .into_iter()
+            .map(char::from)
+            .collect()
+    }
 }
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
     use crate::{
-        encoding::{base64, crockford},
+        encoding::{base64, crockford, hex},
         Hash, HASH_SIZE_BASE64, HASH_SIZE_CROCKFORD,
     };
 
@@ -56,6 +70,7 @@ mod tests {
 
         assert_eq!(h.to_crockford().len(), 77);
         assert_eq!(h.to_base64().len(), 64);
+        assert_eq!(h.to_hex().len(), 96);
     }
 
     #[test]
@@ -64,6 +79,7 @@ mod tests {
 
         assert_eq!(h.to_crockford().as_bytes(), crockford::encode(&h.inner));
         assert_eq!(h.to_base64().as_bytes(), base64::encode(&h.inner));
+        assert_eq!(h.to_hex().as_bytes(), hex::encode(&h.inner));
     }
 
     #[test]
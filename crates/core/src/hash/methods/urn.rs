@@ -0,0 +1,141 @@
+use crate::{HashValidationError, IdentifierError, HASH_SIZE_BIN, MIN_RECOVERABLE_BIN};
+
+use super::super::Hash;
+
+/// The URN namespace identifier of ps-hash.
+const NAMESPACE: &str = "ps-hash";
+
+impl Hash {
+    /// Returns the hash as a URN, `urn:ps-hash:` followed by the canonical
+    /// Crockford Base32 representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps_hash_core::Hash;
+    ///
+    /// let hash = Hash::hash(b"urn")?;
+    /// let urn = hash.to_urn();
+    ///
+    /// assert_eq!(urn, format!("urn:ps-hash:{hash}"));
+    /// assert_eq!(Hash::parse_urn(&urn)?, hash);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn to_urn(&self) -> String {
+        format!("urn:{NAMESPACE}:{self}")
+    }
+
+    /// Parses a URN written by [`Hash::to_urn`].
+    ///
+    /// As RFC 8141 requires, the `urn` scheme and the namespace identifier
+    /// are matched case-insensitively. The embedded hash is checked with
+    /// [`Hash::validate`], so it may be in any text representation that
+    /// accepts, Crockford Base32, base64url or hex, and is repaired if need
+    /// be. Lengths that [`Hash::validate`] would read as binary are rejected,
+    /// since a URN cannot carry raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`IdentifierError::UnsupportedScheme`] if `urn` is not a URN.
+    /// - [`IdentifierError::UnsupportedNamespace`] if the namespace is not
+    ///   `ps-hash`.
+    /// - [`IdentifierError::InvalidSyntax`] if the URN has no namespace.
+    /// - [`IdentifierError::InvalidHash`] if the embedded hash has a binary
+    ///   length, or fails validation.
+    pub fn parse_urn(urn: &str) -> Result<Self, IdentifierError> {
+        let (scheme, rest) = urn.split_once(':').ok_or(IdentifierError::InvalidSyntax)?;
+
+        if !scheme.eq_ignore_ascii_case("urn") {
+            return Err(IdentifierError::UnsupportedScheme(scheme.to_owned()));
+        }
+
+        let (namespace, hash) = rest.split_once(':').ok_or(IdentifierError::InvalidSyntax)?;
+
+        if !namespace.eq_ignore_ascii_case(NAMESPACE) {
+            return Err(IdentifierError::UnsupportedNamespace(namespace.to_owned()));
+        }
+
+        if (MIN_RECOVERABLE_BIN..=HASH_SIZE_BIN).contains(&hash.len()) {
+            return Err(HashValidationError::InvalidLength(hash.len()).into());
+        }
+
+        Ok(Self::validate(hash)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{Hash, HashValidationError, IdentifierError};
+
+    fn hash() -> Hash {
+        Hash::hash(b"urn").expect("hashing should succeed")
+    }
+
+    #[test]
+    fn to_urn_embeds_the_crockford_representation() {
+        let hash = hash();
+        let urn = hash.to_urn();
+
+        assert_eq!(&urn[..12], "urn:ps-hash:");
+        assert_eq!(&urn[12..], hash.to_crockford());
+    }
+
+    #[test]
+    fn parse_urn_is_lenient_where_urns_are() {
+        let hash = hash();
+
+        for urn in [
+            hash.to_urn(),
+            format!("URN:PS-Hash:{}", hash.to_crockford().to_lowercase()),
+            format!("urn:ps-hash:{}", hash.to_base64()),
+            format!("urn:ps-hash:{}", hash.to_hex()),
+            format!("urn:ps-hash:{}", &hash.to_crockford()[..70]),
+        ] {
+            assert_eq!(Hash::parse_urn(&urn), Ok(hash), "{urn}");
+        }
+    }
+
+    #[test]
+    fn parse_urn_rejects_other_identifiers() {
+        let hash = hash();
+
+        assert_eq!(
+            Hash::parse_urn(&format!("url:ps-hash:{hash}")),
+            Err(IdentifierError::UnsupportedScheme("url".to_owned()))
+        );
+        assert_eq!(
+            Hash::parse_urn(&format!("urn:sha256:{hash}")),
+            Err(IdentifierError::UnsupportedNamespace("sha256".to_owned()))
+        );
+        assert_eq!(Hash::parse_urn("urn"), Err(IdentifierError::InvalidSyntax));
+        assert_eq!(
+            Hash::parse_urn("urn:ps-hash"),
+            Err(IdentifierError::InvalidSyntax)
+        );
+        assert_eq!(
+            Hash::parse_urn("urn:ps-hash:"),
+            Err(IdentifierError::InvalidHash(
+                HashValidationError::InvalidLength(0)
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_urn_rejects_binary_lengths() {
+        // Printable bytes of a binary length would otherwise be taken as a
+        // raw hash.
+        for len in [41, 42, 48] {
+            let urn = format!("urn:ps-hash:{}", "A".repeat(len));
+
+            assert_eq!(
+                Hash::parse_urn(&urn),
+                Err(IdentifierError::InvalidHash(
+                    HashValidationError::InvalidLength(len)
+                )),
+                "{urn}"
+            );
+        }
+    }
+}
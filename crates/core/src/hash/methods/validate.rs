@@ -1,9 +1,10 @@
 use ps_ecc::ReedSolomon;
 
 use crate::{
-    encoding::{base64, crockford},
-    HashValidationError, DIGEST_SIZE, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_CROCKFORD,
-    MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD, PARITY_OFFSET,
+    encoding::{base64, crockford, hex},
+    HashValidationError, Representation, DIGEST_SIZE, HASH_SIZE_BASE64, HASH_SIZE_BIN,
+    HASH_SIZE_COMPACT, HASH_SIZE_CROCKFORD, HASH_SIZE_HEX, MIN_RECOVERABLE_BASE64,
+    MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD, MIN_RECOVERABLE_HEX, PARITY_OFFSET,
 };
 
 use super::super::Hash;
@@ -12,7 +13,7 @@ impl Hash {
     /// Validates and, where necessary, repairs a hash in any of its
     /// representations.
     ///
-    /// The representation is selected by input length, since the three
+    /// The representation is selected by input length, since the four
     /// accepted ranges are disjoint:
     ///
     /// | length  | representation            |
@@ -20,6 +21,7 @@ impl Hash {
     /// | 41..=48 | binary, including compact |
     /// | 55..=64 | base64url                 |
     /// | 66..=77 | Crockford Base32          |
+    /// | 82..=96 | hexadecimal               |
     ///
     /// Inputs shorter than the full size are treated as truncated and are
     /// restored by the Reed-Solomon codec, which corrects up to
@@ -36,43 +38,74 @@ impl Hash {
     ///   buffer is a valid Reed-Solomon codeword, so it must be rejected
     ///   explicitly.
     pub fn validate(bytes: impl AsRef<[u8]>) -> Result<Self, HashValidationError> {
-        let bytes = bytes.as_ref();
-
-        let mut hash = Self {
-            // Bytes the input does not determine are filled with 0xF4. The
-            // constant is chosen arbitrarily, but it must not be 0x00: the
-            // all-zero buffer is a valid Reed-Solomon codeword, so relying on
-            // the decoders' zero-fill would turn any input whose bytes they
-            // all skip into Ok(AAA...AAA).
-            inner: match bytes.len() {
-                MIN_RECOVERABLE_BIN..=HASH_SIZE_BIN => {
-                    let mut inner = [0xF4; HASH_SIZE_BIN];
-                    inner[..bytes.len()].copy_from_slice(bytes);
-                    inner
-                }
-                MIN_RECOVERABLE_BASE64..=HASH_SIZE_BASE64 => {
-                    let mut inner = base64::decode(bytes);
-                    inner[base64::decoded_len(bytes)..].fill(0xF4);
-                    inner
-                }
-                MIN_RECOVERABLE_CROCKFORD..=HASH_SIZE_CROCKFORD => {
-                    let mut inner = crockford::decode(bytes);
-                    inner[crockford::decoded_len(bytes)..].fill(0xF4);
-                    inner
-                }
-                len => Err(HashValidationError::InvalidLength(len))?,
-            },
-        };
-
-        let (data, parity) = hash.inner.split_at_mut(PARITY_OFFSET);
+        let (_, received) = Self::receive(bytes.as_ref())?;
+
+        Self::correct(received)
+    }
+
+    /// Decodes `bytes` into the internal representation as received, before
+    /// any correction, along with the representation its length selects.
+    pub(crate) fn receive(
+        bytes: &[u8],
+    ) -> Result<(Representation, [u8; HASH_SIZE_BIN]), HashValidationError> {
+        // Bytes the input does not determine are filled with 0xF4. The
+        // constant is chosen arbitrarily, but it must not be 0x00: the
+        // all-zero buffer is a valid Reed-Solomon codeword, so relying on the
+        // decoders' zero-fill would turn any input whose bytes they all skip
+        // into Ok(AAA...AAA).
+        match bytes.len() {
+            MIN_RECOVERABLE_BIN..=HASH_SIZE_BIN => {
+                let mut inner = [0xF4; HASH_SIZE_BIN];
+                inner[..bytes.len()].copy_from_slice(bytes);
+
+                let representation = if bytes.len() == HASH_SIZE_COMPACT {
+                    Representation::Compact
+                } else {
+                    Representation::Binary
+                };
+
+                Ok((representation, inner))
+            }
+            MIN_RECOVERABLE_BASE64..=HASH_SIZE_BASE64 => {
+                let mut inner = base64::decode(bytes);
+                inner[base64::decoded_len(bytes)..].fill(0xF4);
+
+                Ok((Representation::Base64, inner))
+            }
+            MIN_RECOVERABLE_CROCKFORD..=HASH_SIZE_CROCKFORD => {
+                let mut inner = crockford::decode(bytes);
+                inner[crockford::decoded_len(bytes)..].fill(0xF4);
+
+                Ok((Representation::Crockford, inner))
+            }
+            MIN_RECOVERABLE_HEX..=HASH_SIZE_HEX => {
+                let mut inner = hex::decode(bytes);
+                inner[hex::decoded_len(bytes)..].fill(0xF4);
+
+                Ok((Representation::Hex, inner))
+            }
+            len => Err(HashValidationError::InvalidLength(len)),
+        }
+    }
+
+    /// Corrects a received internal representation and rejects the zero
+    /// digest.
+    pub(crate) fn correct(mut received: [u8; HASH_SIZE_BIN]) -> Result<Self, HashValidationError> {
+        let (data, parity) = received.split_at_mut(PARITY_OFFSET);
 
         ReedSolomon::correct_detached_in_place(parity, data)?;
 
-        if hash.inner[..DIGEST_SIZE] == [0; DIGEST_SIZE] {
+        Self::from_codeword(received)
+    }
+
+    /// Wraps an already corrected internal representation, rejecting the
+    /// zero digest.
+    pub(crate) fn from_codeword(inner: [u8; HASH_SIZE_BIN]) -> Result<Self, HashValidationError> {
+        if inner[..DIGEST_SIZE] == [0; DIGEST_SIZE] {
             return Err(HashValidationError::ZeroDigest);
         }
 
-        Ok(hash)
+        Ok(Self { inner })
     }
 }
 
@@ -81,8 +114,8 @@ impl Hash {
 mod tests {
     use crate::{
         Hash, HashValidationError, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_COMPACT,
-        HASH_SIZE_CROCKFORD, MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN,
-        MIN_RECOVERABLE_CROCKFORD,
+        HASH_SIZE_CROCKFORD, HASH_SIZE_HEX, MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN,
+        MIN_RECOVERABLE_CROCKFORD, MIN_RECOVERABLE_HEX,
     };
 
     /// Replaces the character at `index` with a different one that is valid in
@@ -114,6 +147,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_uncorrupted_hex() {
+        let original = Hash::hash(b"test").expect("hashing should succeed");
+
+        assert_eq!(
+            Hash::validate(original.to_hex())
+                .expect("validation of an uncorrupted hash should succeed"),
+            original
+        );
+        assert_eq!(
+            Hash::validate(original.to_hex().to_uppercase())
+                .expect("validation of an uppercase hash should succeed"),
+            original
+        );
+    }
+
     #[test]
     fn validate_uncorrupted_binary() {
         let original = Hash::hash(b"test").expect("hashing should succeed");
@@ -177,6 +226,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_recovers_corrupt_hex_characters() {
+        let original = Hash::hash(b"hex corruption").expect("hashing should succeed");
+        let mut corrupted = original.to_hex().into_bytes();
+
+        for index in [3, 20, 50, 90] {
+            corrupt(&mut corrupted, index);
+        }
+
+        assert_eq!(
+            Hash::validate(corrupted).expect("corrupted characters should be recovered"),
+            original
+        );
+    }
+
     #[test]
     fn validate_recovers_corrupt_binary_bytes() {
         let original = Hash::hash(b"binary corruption").expect("hashing should succeed");
@@ -210,7 +274,13 @@ mod tests {
 
         let crockford = original.to_crockford();
         let base64 = original.to_base64();
+        let hex = original.to_hex();
 
+        assert_eq!(
+            Hash::validate(&hex[..MIN_RECOVERABLE_HEX])
+                .expect("validation of a minimum-length hash should succeed"),
+            original
+        );
         assert_eq!(
             Hash::validate(&crockford[..MIN_RECOVERABLE_CROCKFORD])
                 .expect("validation of a minimum-length hash should succeed"),
@@ -234,6 +304,14 @@ mod tests {
 
         let crockford = original.to_crockford();
         let base64 = original.to_base64();
+        let hex = original.to_hex();
+
+        for len in MIN_RECOVERABLE_HEX..=HASH_SIZE_HEX {
+            assert_eq!(
+                Hash::validate(&hex[..len]).expect("validation of a truncated hash should succeed"),
+                original
+            );
+        }
 
         for len in MIN_RECOVERABLE_CROCKFORD..=HASH_SIZE_CROCKFORD {
             assert_eq!(
@@ -262,7 +340,7 @@ mod tests {
 
     #[test]
     fn validate_rejects_lengths_between_the_ranges() {
-        for len in [0, 1, 40, 49, 54, 65, 78, 100] {
+        for len in [0, 1, 40, 49, 54, 65, 78, 81, 97, 100] {
             assert_eq!(
                 Hash::validate(vec![b'A'; len]),
                 Err(HashValidationError::InvalidLength(len))
@@ -279,6 +357,7 @@ mod tests {
             vec![b'-'; MIN_RECOVERABLE_CROCKFORD],
             vec![b' '; HASH_SIZE_BASE64],
             vec![b'='; HASH_SIZE_BASE64],
+            vec![b'g'; HASH_SIZE_HEX],
         ] {
             assert!(
                 Hash::validate(&junk).is_err(),
@@ -294,6 +373,7 @@ mod tests {
             vec![0u8; HASH_SIZE_BIN],
             vec![b'A'; HASH_SIZE_BASE64],
             vec![b'0'; HASH_SIZE_CROCKFORD],
+            vec![b'0'; HASH_SIZE_HEX],
         ] {
             assert_eq!(
                 Hash::validate(&input),
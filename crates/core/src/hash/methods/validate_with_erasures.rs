@@ -0,0 +1,251 @@
+use crate::{erasure::correct_with_erasures, HashValidationError, HASH_SIZE_BIN};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Validates and repairs a hash like [`Hash::validate`], treating the
+    /// input characters at `erasures` as known to be unreadable.
+    ///
+    /// The Reed-Solomon codec spends one parity byte on each erased byte but
+    /// two on each error it must also locate, so it recovers any mix of `v`
+    /// unknown errors and `e` erased bytes with `2 * v + e <=`
+    /// [`PARITY_SIZE`](crate::PARITY_SIZE), twice the
+    /// [`PARITY`](crate::PARITY) bytes [`Hash::validate`] recovers. Bytes past
+    /// the end of a truncated input count as erased.
+    ///
+    /// Each position indexes `bytes` and must hold a character that stood for
+    /// a symbol of the representation, whatever it holds now; an erased
+    /// Crockford or base64url character spends every byte it carried bits of.
+    ///
+    /// # Errors
+    ///
+    /// - [`HashValidationError::ErasureOutOfRange`] if a position is not
+    ///   within `bytes`.
+    /// - Otherwise, the same as [`Hash::validate`].
+    pub fn validate_with_erasures(
+        bytes: impl AsRef<[u8]>,
+        erasures: &[usize],
+    ) -> Result<Self, HashValidationError> {
+        let mut bytes = bytes.as_ref().to_vec();
+
+        // '0' is a symbol in every representation, so the decoder consumes
+        // each erased character rather than skipping it, which would shift
+        // every later symbol.
+        for &position in erasures {
+            *bytes
+                .get_mut(position)
+                .ok_or(HashValidationError::ErasureOutOfRange(position))? = b'0';
+        }
+
+        let (representation, mut received) = Self::receive(&bytes)?;
+
+        let positions = representation.symbol_positions(&bytes);
+        let bits = representation.bits_per_symbol();
+
+        let mut offsets: Vec<usize> = erasures
+            .iter()
+            .filter_map(|position| positions.binary_search(position).ok())
+            .flat_map(|symbol| symbol * bits / 8..=(symbol * bits + bits - 1) / 8)
+            .chain(positions.len() * bits / 8..HASH_SIZE_BIN)
+            .filter(|&offset| offset < HASH_SIZE_BIN)
+            .collect();
+
+        offsets.sort_unstable();
+        offsets.dedup();
+
+        // The result is a codeword, so a second, errors-only pass could only
+        // move it to another one.
+        correct_with_erasures(&mut received, &offsets)?;
+
+        Self::from_codeword(received)
+    }
+
+    /// Validates and repairs a textual hash like [`Hash::validate_with_erasures`],
+    /// erasing every occurrence of `placeholder`.
+    ///
+    /// The placeholder must not be a symbol of the representation: `_` and
+    /// `-` belong to the base64url alphabet, so mark unreadable base64url
+    /// characters with `?` instead. Binary inputs are validated as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps_hash_core::Hash;
+    ///
+    /// let hash = Hash::hash(b"transcribed")?;
+    /// let mut transcribed = hash.to_crockford().into_bytes();
+    ///
+    /// // Twelve unreadable characters hide more bytes than `validate` repairs.
+    /// transcribed[20..32].fill(b'?');
+    ///
+    /// assert!(Hash::validate(&transcribed).is_err());
+    /// assert_eq!(Hash::validate_with_placeholders(&transcribed, b'?')?, hash);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Hash::validate`].
+    pub fn validate_with_placeholders(
+        bytes: impl AsRef<[u8]>,
+        placeholder: u8,
+    ) -> Result<Self, HashValidationError> {
+        let bytes = bytes.as_ref();
+
+        if bytes.len() <= HASH_SIZE_BIN {
+            return Self::validate(bytes);
+        }
+
+        let erasures: Vec<usize> = bytes
+            .iter()
+            .enumerate()
+            .filter(|&(_, &byte)| byte == placeholder)
+            .map(|(position, _)| position)
+            .collect();
+
+        Self::validate_with_erasures(bytes, &erasures)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{Hash, HashValidationError, MIN_RECOVERABLE_CROCKFORD, PARITY_SIZE};
+
+    #[test]
+    fn recovers_parity_size_erased_binary_bytes() {
+        let original = Hash::hash(b"binary erasures").expect("hashing should succeed");
+        let mut damaged = original.inner;
+        let erasures: Vec<usize> = (0..PARITY_SIZE).map(|i| i * 3).collect();
+
+        for &offset in &erasures {
+            damaged[offset] = 0;
+        }
+
+        assert!(Hash::validate(damaged).is_err());
+        assert_eq!(
+            Hash::validate_with_erasures(damaged, &erasures),
+            Ok(original)
+        );
+    }
+
+    #[test]
+    fn recovers_erasures_mixed_with_errors() {
+        let original = Hash::hash(b"mixed").expect("hashing should succeed");
+        let mut damaged = original.inner;
+        let erasures = [1, 5, 9, 13, 20, 33, 41, 47];
+
+        for offset in erasures.into_iter().chain([25, 38, 44]) {
+            damaged[offset] ^= 0x3C;
+        }
+
+        assert_eq!(
+            Hash::validate_with_erasures(damaged, &erasures),
+            Ok(original)
+        );
+    }
+
+    #[test]
+    fn recovers_crockford_placeholders() {
+        let original = Hash::hash(b"crockford placeholders").expect("hashing should succeed");
+
+        for placeholder in [b'?', b'_'] {
+            let mut transcribed = original.to_crockford().into_bytes();
+
+            transcribed[40..52].fill(placeholder);
+
+            assert!(Hash::validate(&transcribed).is_err());
+            assert_eq!(
+                Hash::validate_with_placeholders(&transcribed, placeholder),
+                Ok(original)
+            );
+        }
+    }
+
+    #[test]
+    fn recovers_base64_placeholders() {
+        let original = Hash::hash(b"base64 placeholders").expect("hashing should succeed");
+        let mut transcribed = original.to_base64().into_bytes();
+
+        // Sixteen characters carry twelve whole bytes.
+        transcribed[8..24].fill(b'?');
+
+        assert!(Hash::validate(&transcribed).is_err());
+        assert_eq!(
+            Hash::validate_with_placeholders(&transcribed, b'?'),
+            Ok(original)
+        );
+    }
+
+    #[test]
+    fn counts_truncated_bytes_as_erased() {
+        let original = Hash::hash(b"truncated erasures").expect("hashing should succeed");
+        let crockford = original.to_crockford();
+        let mut transcribed = crockford.as_bytes()[..MIN_RECOVERABLE_CROCKFORD].to_vec();
+
+        transcribed[..5].fill(b'?');
+
+        assert!(Hash::validate(&transcribed).is_err());
+        assert_eq!(
+            Hash::validate_with_placeholders(&transcribed, b'?'),
+            Ok(original)
+        );
+    }
+
+    #[test]
+    fn rejects_more_erasures_than_parity_bytes() {
+        let original = Hash::hash(b"too many").expect("hashing should succeed");
+        let erasures: Vec<usize> = (0..=PARITY_SIZE).collect();
+        let mut damaged = original.inner;
+
+        for &offset in &erasures {
+            damaged[offset] ^= 0xFF;
+        }
+
+        assert!(matches!(
+            Hash::validate_with_erasures(damaged, &erasures),
+            Err(HashValidationError::RSDecodeError(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_errors_and_erasures_just_over_the_budget() {
+        let original = Hash::hash(b"over budget").expect("hashing should succeed");
+        let mut damaged = original.inner;
+        let erasures = [2, 7, 11, 19];
+
+        // 2 * 6 + 4 = 16 exceeds the 14 parity bytes.
+        for offset in erasures.into_iter().chain([23, 28, 31, 36, 40, 45]) {
+            damaged[offset] ^= 0x5A;
+        }
+
+        assert!(matches!(
+            Hash::validate_with_erasures(damaged, &erasures),
+            Err(HashValidationError::RSDecodeError(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_erasures_outside_the_input() {
+        let original = Hash::hash(b"out of range").expect("hashing should succeed");
+
+        assert_eq!(
+            Hash::validate_with_erasures(original.to_crockford(), &[77]),
+            Err(HashValidationError::ErasureOutOfRange(77))
+        );
+    }
+
+    #[test]
+    fn matches_validate_without_erasures() {
+        let original = Hash::hash(b"no erasures").expect("hashing should succeed");
+
+        assert_eq!(
+            Hash::validate_with_placeholders(original.to_base64(), b'?'),
+            Ok(original)
+        );
+        assert_eq!(
+            Hash::validate_with_erasures(original.compact(), &[]),
+            Ok(original)
+        );
+    }
+}
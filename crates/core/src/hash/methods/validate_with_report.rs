@@ -0,0 +1,212 @@
+use std::ops::Range;
+
+use crate::{Correction, HashValidationError, ValidationReport, HASH_SIZE_BIN};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Validates and repairs a hash like [`Hash::validate`], and reports what
+    /// was repaired.
+    ///
+    /// The [`ValidationReport`] names the detected representation, whether
+    /// the input was truncated, and every byte the Reed-Solomon codec
+    /// corrected, with its offset in the internal representation and the
+    /// input characters it was decoded from. Storage whose hashes need ever
+    /// more corrections is drifting towards the [`PARITY`](crate::PARITY)
+    /// limit, past which validation fails.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`Hash::validate`].
+    pub fn validate_with_report(
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<(Self, ValidationReport), HashValidationError> {
+        let bytes = bytes.as_ref();
+
+        let (representation, received) = Self::receive(bytes)?;
+        let hash = Self::correct(received)?;
+
+        let positions = representation.symbol_positions(bytes);
+        let bits = representation.bits_per_symbol();
+
+        let corrections = (0..HASH_SIZE_BIN)
+            .filter(|&offset| received[offset] != hash.inner[offset])
+            .map(|offset| Correction::new(offset, input_range(&positions, bits, offset, bytes)))
+            .collect();
+
+        let truncated = bytes.len() < representation.full_size();
+
+        Ok((
+            hash,
+            ValidationReport::new(representation, truncated, corrections),
+        ))
+    }
+}
+
+/// Maps the byte at `offset` back to the input positions of the symbols that
+/// carried its bits.
+fn input_range(positions: &[usize], bits: usize, offset: usize, bytes: &[u8]) -> Range<usize> {
+    let first = offset * 8 / bits;
+    let last = (offset * 8 + 7) / bits;
+
+    match (positions.get(first), positions.len().checked_sub(1)) {
+        (Some(&start), Some(available)) => start..positions[last.min(available)] + 1,
+        _ => bytes.len()..bytes.len(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{
+        encoding::crockford, Hash, HashValidationError, Representation, HASH_SIZE_COMPACT,
+        MIN_RECOVERABLE_CROCKFORD, PARITY,
+    };
+
+    fn corrupt(bytes: &mut [u8], index: usize) {
+        bytes[index] = if bytes[index] == b'A' { b'B' } else { b'A' };
+    }
+
+    #[test]
+    fn report_is_clean_for_uncorrupted_input() {
+        let original = Hash::hash(b"clean").expect("hashing should succeed");
+
+        for (input, representation) in [
+            (
+                original.to_crockford().into_bytes(),
+                Representation::Crockford,
+            ),
+            (original.to_base64().into_bytes(), Representation::Base64),
+            (original.inner.to_vec(), Representation::Binary),
+        ] {
+            let (hash, report) =
+                Hash::validate_with_report(&input).expect("validation should succeed");
+
+            assert_eq!(hash, original);
+            assert_eq!(report.representation(), representation);
+            assert!(!report.is_truncated());
+            assert_eq!(report.corrected_bytes(), 0);
+        }
+    }
+
+    #[test]
+    fn report_locates_corrupted_binary_bytes() {
+        let original = Hash::hash(b"binary").expect("hashing should succeed");
+        let mut corrupted = original.inner;
+
+        for offset in [3, 17, 40] {
+            corrupted[offset] ^= 0x55;
+        }
+
+        let (hash, report) =
+            Hash::validate_with_report(corrupted).expect("validation should succeed");
+
+        assert_eq!(hash, original);
+        assert_eq!(report.corrected_bytes(), 3);
+
+        for (correction, offset) in report.corrections().iter().zip([3, 17, 40]) {
+            assert_eq!(correction.offset(), offset);
+            assert_eq!(correction.input(), offset..offset + 1);
+        }
+    }
+
+    #[test]
+    fn report_maps_corrections_back_to_crockford_characters() {
+        let original = Hash::hash(b"crockford").expect("hashing should succeed");
+        let mut corrupted = original.to_crockford().into_bytes();
+
+        for index in [4, 30, 60] {
+            corrupt(&mut corrupted, index);
+        }
+
+        let (hash, report) =
+            Hash::validate_with_report(&corrupted).expect("validation should succeed");
+
+        assert_eq!(hash, original);
+        assert!(report.corrected_bytes() <= PARITY as usize);
+
+        for index in [4, 30, 60] {
+            assert!(
+                report
+                    .corrections()
+                    .iter()
+                    .any(|correction| correction.input().contains(&index)),
+                "no correction maps back to character {index}"
+            );
+        }
+
+        for correction in report.corrections() {
+            assert!(
+                [4, 30, 60]
+                    .iter()
+                    .any(|index| correction.input().contains(index)),
+                "correction at {} maps to no corrupted character",
+                correction.offset()
+            );
+        }
+    }
+
+    #[test]
+    fn report_accounts_for_skipped_characters() {
+        let original = Hash::hash(b"skipped").expect("hashing should succeed");
+        let mut corrupted = original.to_base64().into_bytes();
+
+        // Character 40 carries the top six bits of byte 30, whose remaining
+        // two bits are in character 41. After the inserted space, the two sit
+        // at input positions 41 and 42.
+        corrupt(&mut corrupted, 40);
+        corrupted.pop();
+        corrupted.insert(20, b' ');
+
+        let (hash, report) =
+            Hash::validate_with_report(&corrupted).expect("validation should succeed");
+
+        assert_eq!(hash, original);
+        assert!(report
+            .corrections()
+            .iter()
+            .any(|correction| correction.offset() == 30 && correction.input() == (41..43)));
+    }
+
+    #[test]
+    fn report_flags_truncated_input() {
+        let original = Hash::hash(b"truncated").expect("hashing should succeed");
+        let crockford = original.to_crockford();
+        let truncated = &crockford.as_bytes()[..MIN_RECOVERABLE_CROCKFORD];
+
+        let (hash, report) =
+            Hash::validate_with_report(truncated).expect("validation should succeed");
+
+        assert_eq!(hash, original);
+        assert!(report.is_truncated());
+
+        for correction in report.corrections() {
+            assert!(correction.offset() >= crockford::decoded_len(truncated));
+        }
+    }
+
+    #[test]
+    fn report_detects_the_compact_representation() {
+        let original = Hash::hash(b"compact").expect("hashing should succeed");
+
+        let (hash, report) =
+            Hash::validate_with_report(original.compact()).expect("validation should succeed");
+
+        assert_eq!(hash, original);
+        assert_eq!(report.representation(), Representation::Compact);
+        assert!(!report.is_truncated());
+
+        for correction in report.corrections() {
+            assert!(correction.offset() >= HASH_SIZE_COMPACT);
+            assert!(correction.input().is_empty());
+        }
+    }
+
+    #[test]
+    fn report_fails_like_validate() {
+        assert_eq!(
+            Hash::validate_with_report("short"),
+            Err(HashValidationError::InvalidLength(5))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use ps_pint16::PackedInt;
+
+use crate::{Hasher, VerifyError};
+
+use super::super::Hash;
+
+impl Hash {
+    /// Checks that `data` is the input this hash was computed from.
+    ///
+    /// The length field is compared first, so data of the wrong length is
+    /// rejected without being hashed.
+    ///
+    /// # Errors
+    ///
+    /// - [`VerifyError::LengthMismatch`] if the length of `data` does not
+    ///   match [`Hash::data_max_len`].
+    /// - [`VerifyError::DigestMismatch`] if the length matches but the content
+    ///   does not.
+    /// - [`VerifyError::HashError`] if hashing fails.
+    pub fn verify(&self, data: impl AsRef<[u8]>) -> Result<(), VerifyError> {
+        let data = data.as_ref();
+
+        self.verify_len(data.len())?;
+
+        if Self::hash(data)? != *self {
+            return Err(VerifyError::DigestMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `len` bytes is consistent with the stored length field.
+    pub(crate) fn verify_len(&self, len: usize) -> Result<(), VerifyError> {
+        let expected = self.data_max_len();
+
+        if PackedInt::from_usize(len) != expected {
+            return Err(VerifyError::LengthMismatch {
+                expected,
+                actual: len,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the input fed to `hasher` is the input this hash was
+    /// computed from.
+    pub(crate) fn verify_hasher(&self, hasher: &Hasher) -> Result<(), VerifyError> {
+        self.verify_len(hasher.len())?;
+
+        if hasher.finalize()? != *self {
+            return Err(VerifyError::DigestMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use ps_pint16::PackedInt;
+
+    use crate::{Hash, VerifyError};
+
+    #[test]
+    fn verify_accepts_the_hashed_data() {
+        let hash = Hash::hash(b"verify me").expect("hashing should succeed");
+
+        assert_eq!(hash.verify(b"verify me"), Ok(()));
+    }
+
+    #[test]
+    fn verify_accepts_the_empty_input() {
+        let hash = Hash::hash([]).expect("hashing should succeed");
+
+        assert_eq!(hash.verify([]), Ok(()));
+    }
+
+    #[test]
+    fn verify_rejects_a_length_mismatch() {
+        let hash = Hash::hash(b"verify me").expect("hashing should succeed");
+
+        assert_eq!(
+            hash.verify(b"verify me!"),
+            Err(VerifyError::LengthMismatch {
+                expected: PackedInt::from_usize(9),
+                actual: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_a_digest_mismatch() {
+        let hash = Hash::hash(b"verify me").expect("hashing should succeed");
+
+        assert_eq!(hash.verify(b"verify us"), Err(VerifyError::DigestMismatch));
+    }
+
+    #[test]
+    fn verify_accepts_data_after_validation() {
+        let data = vec![0x5A; 100_000];
+        let hash = Hash::hash(&data).expect("hashing should succeed");
+        let validated =
+            Hash::validate(hash.to_base64()).expect("validation of a hash should succeed");
+
+        assert_eq!(validated.verify(&data), Ok(()));
+    }
+}
@@ -0,0 +1,168 @@
+use std::io;
+
+use ps_pint16::PackedInt;
+use sha2::{Digest, Sha256};
+
+use crate::{inner::combine_digests, inner_from_parts, Hash, HashError, DIGEST_SIZE};
+
+/// Incrementally hashes input that arrives in pieces.
+///
+/// Feeding the pieces of an input through [`Hasher::update`] and calling
+/// [`Hasher::finalize`] produces the same [`Hash`](struct@Hash) as
+/// [`Hash::hash`] on the concatenated input, without ever holding the whole
+/// input in memory.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_core::{Hash, Hasher};
+///
+/// let mut hasher = Hasher::new();
+///
+/// hasher.update(b"Hello, ").update(b"world!");
+///
+/// assert_eq!(hasher.finalize()?, Hash::hash(b"Hello, world!")?);
+/// # Ok::<(), ps_hash_core::HashError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Hasher {
+    sha: Sha256,
+    blake: blake3::Hasher,
+    len: usize,
+}
+
+impl Hasher {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds `data` into both digests.
+    pub fn update(&mut self, data: &[u8]) -> &mut Self {
+        self.sha.update(data);
+        self.blake.update(data);
+        self.len = self.len.saturating_add(data.len());
+
+        self
+    }
+
+    /// Returns the number of bytes fed so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes have been fed yet.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Produces the [`Hash`](struct@Hash) of every byte fed so far.
+    ///
+    /// The hasher is left untouched, so more input may be fed afterwards.
+    pub fn finalize(&self) -> Result<Hash, HashError> {
+        let sha: [u8; DIGEST_SIZE] = self.sha.clone().finalize().into();
+        let digest = combine_digests(&sha, self.blake.finalize().as_bytes());
+        let inner = inner_from_parts(&digest, PackedInt::from_usize(self.len))?;
+
+        Ok(Hash { inner })
+    }
+}
+
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::{self, Write};
+
+    use super::Hasher;
+    use crate::Hash;
+
+    #[test]
+    fn empty_hasher_matches_hash_of_empty_input() {
+        let hasher = Hasher::new();
+
+        assert!(hasher.is_empty());
+        assert_eq!(
+            hasher.finalize().expect("finalize should succeed"),
+            Hash::hash([]).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn every_split_matches_one_shot_hash() {
+        let data: Vec<u8> = (0..=255).collect();
+        let expected = Hash::hash(&data).expect("hashing should succeed");
+
+        for split in 0..=data.len() {
+            let mut hasher = Hasher::new();
+
+            hasher.update(&data[..split]).update(&data[split..]);
+
+            assert_eq!(
+                hasher.finalize().expect("finalize should succeed"),
+                expected,
+                "split at {split}"
+            );
+        }
+    }
+
+    #[test]
+    fn many_small_updates_match_one_shot_hash() {
+        let data = vec![0x5A; 100_000];
+        let mut hasher = Hasher::new();
+
+        for chunk in data.chunks(7) {
+            hasher.update(chunk);
+        }
+
+        assert_eq!(hasher.len(), data.len());
+        assert_eq!(
+            hasher.finalize().expect("finalize should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn finalize_does_not_consume_the_state() {
+        let mut hasher = Hasher::new();
+
+        hasher.update(b"first");
+
+        let first = hasher.finalize().expect("finalize should succeed");
+
+        hasher.update(b" second");
+
+        assert_eq!(first, Hash::hash(b"first").expect("hashing should succeed"));
+        assert_eq!(
+            hasher.finalize().expect("finalize should succeed"),
+            Hash::hash(b"first second").expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn io_copy_matches_one_shot_hash() {
+        let data = b"copied through std::io".repeat(1000);
+        let mut hasher = Hasher::new();
+
+        io::copy(&mut data.as_slice(), &mut hasher).expect("copy should succeed");
+        hasher.flush().expect("flush should succeed");
+
+        assert_eq!(
+            hasher.finalize().expect("finalize should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+}
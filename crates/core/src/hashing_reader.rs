@@ -0,0 +1,156 @@
+use std::io::{self, Read};
+
+use crate::{Hash, HashError, Hasher};
+
+/// Forwards the bytes of an inner reader while hashing them.
+///
+/// This lets a consumer store and hash a stream in a single pass: read
+/// through the wrapper as usual, then call [`HashingReader::finalize`] for the
+/// [`Hash`](struct@Hash) of everything that passed through. With the `tokio`
+/// feature enabled, the wrapper is also an
+/// [`AsyncRead`](tokio::io::AsyncRead) over an async inner reader.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+///
+/// use ps_hash_core::{Hash, HashingReader};
+///
+/// let mut reader = HashingReader::new(b"pass-through".as_slice());
+/// let mut stored = Vec::new();
+///
+/// reader.read_to_end(&mut stored)?;
+///
+/// assert_eq!(stored, b"pass-through");
+/// assert_eq!(reader.finalize()?, Hash::hash(b"pass-through")?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct HashingReader<R> {
+    reader: R,
+    hasher: Hasher,
+}
+
+impl<R> HashingReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            hasher: Hasher::new(),
+        }
+    }
+
+    /// Returns the hasher holding everything read so far.
+    pub const fn hasher(&self) -> &Hasher {
+        &self.hasher
+    }
+
+    /// Produces the [`Hash`](struct@Hash) of every byte read so far.
+    pub fn finalize(&self) -> Result<Hash, HashError> {
+        self.hasher.finalize()
+    }
+
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns the inner reader.
+    ///
+    /// Bytes read from it directly bypass the hasher.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+
+        self.hasher.update(&buf[..read]);
+
+        Ok(read)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for HashingReader<R> {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<io::Result<()>> {
+        let this = &mut *self;
+        let filled = buf.filled().len();
+
+        std::task::ready!(std::pin::Pin::new(&mut this.reader).poll_read(cx, buf))?;
+
+        this.hasher.update(&buf.filled()[filled..]);
+
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::Read;
+
+    use super::HashingReader;
+    use crate::Hash;
+
+    #[test]
+    fn forwards_and_hashes_everything_read() {
+        let data = b"forwarded".repeat(1000);
+        let mut reader = HashingReader::new(data.as_slice());
+        let mut forwarded = Vec::new();
+
+        reader
+            .read_to_end(&mut forwarded)
+            .expect("reading should succeed");
+
+        assert_eq!(forwarded, data);
+        assert_eq!(reader.hasher().len(), data.len());
+        assert_eq!(
+            reader.finalize().expect("finalize should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn hashes_only_what_was_read() {
+        let mut reader = HashingReader::new(b"partial read".as_slice());
+        let mut buffer = [0u8; 7];
+
+        reader
+            .read_exact(&mut buffer)
+            .expect("reading should succeed");
+
+        assert_eq!(
+            reader.finalize().expect("finalize should succeed"),
+            Hash::hash(b"partial").expect("hashing should succeed")
+        );
+        assert_eq!(reader.into_inner(), b" read");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn forwards_and_hashes_async_reads() {
+        let data = b"async forwarded".repeat(1000);
+        let mut reader = HashingReader::new(data.as_slice());
+        let mut forwarded = Vec::new();
+
+        tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut forwarded)
+            .await
+            .expect("reading should succeed");
+
+        assert_eq!(forwarded, data);
+        assert_eq!(
+            reader.finalize().expect("finalize should succeed"),
+            Hash::hash(&data).expect("hashing should succeed")
+        );
+    }
+}
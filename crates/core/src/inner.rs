@@ -1,26 +1,132 @@
 use ps_ecc::{RSGenerateParityError, ReedSolomon};
 use ps_pint16::PackedInt;
 
-use crate::{blake3, sha256, DIGEST_SIZE, HASH_SIZE_BIN, PARITY, PARITY_OFFSET};
+use crate::{
+    blake3, blake3_keyed, blake3_with_context, hmac_sha256, sha256, sha256_with_context,
+    DIGEST_SIZE, HASH_SIZE_BIN, PARITY, PARITY_OFFSET,
+};
 
 const RS: ReedSolomon = match ReedSolomon::new(PARITY) {
     Ok(rs) => rs,
     Err(_) => panic!("Failed to construct Reed-Solomon codec."),
 };
 
+/// Smallest input whose two digests are computed concurrently, with BLAKE3
+/// itself spread across the thread pool.
+///
+/// Below this size, the cost of dispatching work to other threads outweighs
+/// the gain.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 128 * 1024;
+
 pub fn hash_inner(data: &[u8]) -> Result<[u8; HASH_SIZE_BIN], RSGenerateParityError> {
-    let mut digest = [0u8; DIGEST_SIZE];
+    let (sha, blake) = digests(data);
+    let digest = combine_digests(&sha, blake.as_bytes());
 
-    let sha = sha256(data);
-    let blake = blake3(data);
+    inner_from_parts(&digest, PackedInt::from_usize(data.len()))
+}
 
-    for i in 0..DIGEST_SIZE {
-        digest[i] = sha[i] ^ blake.as_bytes()[i];
+/// Computes the internal representation of `data` under `key`, with BLAKE3
+/// in keyed mode and HMAC-SHA-256 in place of the plain digests.
+pub fn hash_inner_keyed(
+    key: &[u8; DIGEST_SIZE],
+    data: &[u8],
+) -> Result<[u8; HASH_SIZE_BIN], RSGenerateParityError> {
+    let (sha, blake) = keyed_digests(key, data);
+    let digest = combine_digests(&sha, blake.as_bytes());
+
+    inner_from_parts(&digest, PackedInt::from_usize(data.len()))
+}
+
+#[cfg(not(feature = "rayon"))]
+fn keyed_digests(key: &[u8; DIGEST_SIZE], data: &[u8]) -> ([u8; DIGEST_SIZE], blake3::Hash) {
+    (hmac_sha256(key, data), blake3_keyed(key, data))
+}
+
+#[cfg(feature = "rayon")]
+fn keyed_digests(key: &[u8; DIGEST_SIZE], data: &[u8]) -> ([u8; DIGEST_SIZE], blake3::Hash) {
+    if data.len() < PARALLEL_THRESHOLD {
+        return (hmac_sha256(key, data), blake3_keyed(key, data));
     }
 
+    rayon::join(
+        || hmac_sha256(key, data),
+        || blake3::Hasher::new_keyed(key).update_rayon(data).finalize(),
+    )
+}
+
+/// Computes the internal representation of `data` in the domain named by
+/// `context`, with BLAKE3 in key derivation mode and SHA-256 over the
+/// length-prefixed context followed by `data`.
+pub fn hash_inner_with_context(
+    context: &str,
+    data: &[u8],
+) -> Result<[u8; HASH_SIZE_BIN], RSGenerateParityError> {
+    let (sha, blake) = context_digests(context, data);
+    let digest = combine_digests(&sha, blake.as_bytes());
+
     inner_from_parts(&digest, PackedInt::from_usize(data.len()))
 }
 
+#[cfg(not(feature = "rayon"))]
+fn context_digests(context: &str, data: &[u8]) -> ([u8; DIGEST_SIZE], blake3::Hash) {
+    (
+        sha256_with_context(context, data),
+        blake3_with_context(context, data),
+    )
+}
+
+#[cfg(feature = "rayon")]
+fn context_digests(context: &str, data: &[u8]) -> ([u8; DIGEST_SIZE], blake3::Hash) {
+    if data.len() < PARALLEL_THRESHOLD {
+        return (
+            sha256_with_context(context, data),
+            blake3_with_context(context, data),
+        );
+    }
+
+    rayon::join(
+        || sha256_with_context(context, data),
+        || {
+            blake3::Hasher::new_derive_key(context)
+                .update_rayon(data)
+                .finalize()
+        },
+    )
+}
+
+#[cfg(not(feature = "rayon"))]
+fn digests(data: &[u8]) -> ([u8; DIGEST_SIZE], blake3::Hash) {
+    (sha256(data), blake3(data))
+}
+
+#[cfg(feature = "rayon")]
+fn digests(data: &[u8]) -> ([u8; DIGEST_SIZE], blake3::Hash) {
+    if data.len() < PARALLEL_THRESHOLD {
+        return (sha256(data), blake3(data));
+    }
+
+    rayon::join(
+        || sha256(data),
+        || blake3::Hasher::new().update_rayon(data).finalize(),
+    )
+}
+
+/// Combines the SHA-256 and BLAKE3 digests of the same input into the stored
+/// digest.
+pub(crate) fn combine_digests(
+    sha: &[u8; DIGEST_SIZE],
+    blake: &[u8; DIGEST_SIZE],
+) -> [u8; DIGEST_SIZE] {
+    let mut digest = [0u8; DIGEST_SIZE];
+
+    for i in 0..DIGEST_SIZE {
+        digest[i] = sha[i] ^ blake[i];
+    }
+
+    digest
+}
+
 /// Assembles the internal representation from its stored parts, regenerating
 /// the parity block.
 pub fn inner_from_parts(
@@ -44,7 +150,11 @@ pub fn inner_from_parts(
 mod tests {
     use ps_pint16::PackedInt;
 
-    use super::{hash_inner, DIGEST_SIZE, HASH_SIZE_BIN, PARITY_OFFSET};
+    use super::{
+        blake3, blake3_keyed, blake3_with_context, combine_digests, hash_inner, hash_inner_keyed,
+        hash_inner_with_context, hmac_sha256, sha256, sha256_with_context, DIGEST_SIZE,
+        HASH_SIZE_BIN, PARITY_OFFSET,
+    };
 
     #[test]
     fn hash_inner_size_is_stable() {
@@ -63,8 +173,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hash_inner_matches_the_sequential_digests_for_large_input() {
+        let data: Vec<u8> = (0..1_000_000u32)
+            .map(|index| index.to_le_bytes()[0])
+            .collect();
+        let digest = combine_digests(&sha256(&data), blake3(&data).as_bytes());
+        let inner = hash_inner(&data).expect("hash_inner should work");
+
+        assert_eq!(&inner[..DIGEST_SIZE], &digest);
+    }
+
     #[test]
     fn hash_inner_length_field_is_byte_aligned() {
         assert_eq!(PARITY_OFFSET - DIGEST_SIZE, 2);
     }
+
+    #[test]
+    fn hash_inner_keyed_matches_the_sequential_digests_for_large_input() {
+        let key = [3u8; DIGEST_SIZE];
+        let data: Vec<u8> = (0..1_000_000u32)
+            .map(|index| index.to_le_bytes()[1])
+            .collect();
+        let digest = combine_digests(
+            &hmac_sha256(&key, &data),
+            blake3_keyed(&key, &data).as_bytes(),
+        );
+        let inner = hash_inner_keyed(&key, &data).expect("hash_inner_keyed should work");
+
+        assert_eq!(&inner[..DIGEST_SIZE], &digest);
+    }
+
+    #[test]
+    fn hash_inner_with_context_matches_the_sequential_digests_for_large_input() {
+        let data: Vec<u8> = (0..1_000_000u32)
+            .map(|index| index.to_le_bytes()[2])
+            .collect();
+        let digest = combine_digests(
+            &sha256_with_context("large", &data),
+            blake3_with_context("large", &data).as_bytes(),
+        );
+        let inner =
+            hash_inner_with_context("large", &data).expect("hash_inner_with_context should work");
+
+        assert_eq!(&inner[..DIGEST_SIZE], &digest);
+    }
 }
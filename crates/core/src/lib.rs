@@ -1,27 +1,55 @@
+mod batch;
+mod build_hasher;
 mod constants;
 mod digest;
 mod encode;
+mod erasure;
 mod error;
 mod hash;
+mod hasher;
+mod hashing_reader;
 mod inner;
+mod prefix_index;
+mod report;
+mod verifying_reader;
 
+pub mod content_hash;
 pub mod encoding;
+pub mod merkle;
+pub mod multiformats;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod golden;
 
+pub use batch::hash_many;
+#[cfg(feature = "rayon")]
+pub use batch::ParallelHashIterator;
+pub use build_hasher::{HashBuildHasher, HashHasher, HashMap, HashSet};
 pub use constants::{
     DIGEST_SIZE, HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_COMPACT, HASH_SIZE_CROCKFORD,
-    MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD, PARITY, PARITY_OFFSET,
-    PARITY_SIZE, SIZE_SIZE,
+    HASH_SIZE_HEX, MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD,
+    MIN_RECOVERABLE_HEX, PARITY, PARITY_OFFSET, PARITY_SIZE, SIZE_SIZE,
+};
+pub use content_hash::ContentHash;
+pub use digest::{
+    blake3, blake3_keyed, blake3_with_context, hmac_sha256, sha256, sha256_with_context,
 };
-pub use digest::{blake3, sha256};
 pub use encode::hash_encoded;
-pub use error::{HashError, HashValidationError};
+pub use error::{
+    HashError, HashReadError, HashValidationError, IdentifierError, MerkleError, MultiformatError,
+    PrefixError, VerifyError,
+};
 pub use hash::{hash, Hash, RS};
-pub use inner::{hash_inner, inner_from_parts};
+pub use hasher::Hasher;
+pub use hashing_reader::HashingReader;
+pub use inner::{hash_inner, hash_inner_keyed, hash_inner_with_context, inner_from_parts};
+pub use prefix_index::PrefixIndex;
 pub use ps_pint16::PackedInt;
+pub use report::{Correction, Representation, ValidationReport};
+pub use verifying_reader::VerifyingReader;
 
 #[allow(clippy::expect_used)]
 #[cfg(test)]
@@ -0,0 +1,133 @@
+//! Binary Merkle trees over [`Hash`](struct@Hash) values, with inclusion
+//! proofs.
+//!
+//! A [`MerkleTree`] commits to a list of hashes with a single root hash, and a
+//! [`MerkleProof`] shows that one of them is in the list at a given position,
+//! using one sibling hash per level instead of the whole list.
+//!
+//! Leaves and interior nodes are hashed with different one-byte prefixes, so
+//! an interior node can never pass for a leaf. The tree has the shape of
+//! RFC 9162's: on each level, adjacent nodes are paired from the left and a
+//! node left without a partner is promoted unchanged, so no node is ever
+//! duplicated.
+//!
+//! # Examples
+//!
+//! ```
+//! use ps_hash_core::{merkle::MerkleTree, Hash};
+//!
+//! let items = ["alpha", "beta", "gamma"]
+//!     .iter()
+//!     .map(Hash::hash)
+//!     .collect::<Result<Vec<_>, _>>()?;
+//!
+//! let tree = MerkleTree::new(&items)?;
+//! let proof = tree.proof(1)?;
+//!
+//! proof.verify(&items[1], &tree.root())?;
+//! assert!(proof.verify(&items[2], &tree.root()).is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod proof;
+mod tree;
+
+pub use proof::MerkleProof;
+pub use tree::MerkleTree;
+
+use crate::{Hash, HashError, Hasher};
+
+/// The prefix of a leaf's hash input.
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// The prefix of an interior node's hash input.
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Hashes the leaf for `item`: [`LEAF_PREFIX`], then the item's bytes.
+///
+/// # Errors
+///
+/// Fails only if hashing fails.
+pub fn leaf_hash(item: &Hash) -> Result<Hash, HashError> {
+    Hasher::new()
+        .update(&[LEAF_PREFIX])
+        .update(item.as_bytes())
+        .finalize()
+}
+
+/// Hashes the interior node over `left` and `right`: [`NODE_PREFIX`], then
+/// the children's bytes, in order.
+///
+/// # Errors
+///
+/// Fails only if hashing fails.
+pub fn node_hash(left: &Hash, right: &Hash) -> Result<Hash, HashError> {
+    Hasher::new()
+        .update(&[NODE_PREFIX])
+        .update(left.as_bytes())
+        .update(right.as_bytes())
+        .finalize()
+}
+
+/// Returns the number of siblings on the path from leaf `index` to the root
+/// of a tree of `leaf_count` leaves.
+///
+/// A node without a partner is promoted, so its level contributes none.
+pub(crate) const fn path_len(index: u64, leaf_count: u64) -> usize {
+    let mut len = 0;
+    let mut index = index;
+    let mut last = leaf_count.saturating_sub(1);
+
+    while last > 0 {
+        if index != last || index % 2 == 1 {
+            len += 1;
+        }
+
+        index >>= 1;
+        last >>= 1;
+    }
+
+    len
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{leaf_hash, node_hash, path_len};
+    use crate::Hash;
+
+    #[test]
+    fn leaves_and_nodes_are_domain_separated() {
+        let item = Hash::hash(b"item").expect("hashing should succeed");
+        let leaf = leaf_hash(&item).expect("hashing should succeed");
+        let node = node_hash(&item, &item).expect("hashing should succeed");
+
+        assert_ne!(leaf, item);
+        assert_ne!(leaf, node);
+        assert_eq!(
+            leaf,
+            Hash::hash([&[0][..], item.as_bytes()].concat()).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn node_hash_is_ordered() {
+        let a = Hash::hash(b"a").expect("hashing should succeed");
+        let b = Hash::hash(b"b").expect("hashing should succeed");
+
+        assert_ne!(
+            node_hash(&a, &b).expect("hashing should succeed"),
+            node_hash(&b, &a).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn path_len_skips_promoted_levels() {
+        assert_eq!(path_len(0, 1), 0);
+        assert_eq!(path_len(0, 2), 1);
+        assert_eq!(path_len(2, 3), 1);
+        assert_eq!(path_len(0, 3), 2);
+        assert_eq!(path_len(4, 5), 1);
+        assert_eq!(path_len(3, 8), 3);
+    }
+}
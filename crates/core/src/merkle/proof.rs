@@ -0,0 +1,365 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Hash, HashError, HashValidationError, MerkleError, HASH_SIZE_BIN};
+
+use super::{leaf_hash, node_hash, path_len};
+
+/// The size of the binary encoding's header: the index and the leaf count.
+const HEADER_SIZE: usize = 16;
+
+/// A proof that an item is in a [`MerkleTree`](super::MerkleTree) at a given
+/// position.
+///
+/// The proof lists the sibling of every node on the path from the item's
+/// leaf to the root, bottom-up. It has two stable encodings:
+///
+/// - [`MerkleProof::to_bytes`]: the index and the leaf count as
+///   little-endian `u64`s, then the [`HASH_SIZE_BIN`] bytes of each sibling.
+/// - [`Display`](fmt::Display): the index and the leaf count separated by
+///   `/`, then the canonical Crockford Base32 form of each sibling, each
+///   preceded by `:`, as in `2/3:<sibling>`. [`FromStr`] parses it back.
+///
+/// Both decoders accept only canonical, error-free hashes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    index: u64,
+    leaf_count: u64,
+    siblings: Vec<Hash>,
+}
+
+impl MerkleProof {
+    /// Assembles a proof for leaf `index` of a tree of `leaf_count` leaves.
+    ///
+    /// # Errors
+    ///
+    /// - [`MerkleError::IndexOutOfRange`] is returned if `index` is not less
+    ///   than `leaf_count`.
+    /// - [`MerkleError::InvalidPathLength`] is returned if the number of
+    ///   siblings does not match the path from `index` to the root.
+    pub fn new(index: u64, leaf_count: u64, siblings: Vec<Hash>) -> Result<Self, MerkleError> {
+        if index >= leaf_count {
+            return Err(MerkleError::IndexOutOfRange { index, leaf_count });
+        }
+
+        let expected = path_len(index, leaf_count);
+
+        if siblings.len() != expected {
+            return Err(MerkleError::InvalidPathLength {
+                expected,
+                actual: siblings.len(),
+            });
+        }
+
+        Ok(Self {
+            index,
+            leaf_count,
+            siblings,
+        })
+    }
+
+    pub const fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub const fn leaf_count(&self) -> u64 {
+        self.leaf_count
+    }
+
+    pub fn siblings(&self) -> &[Hash] {
+        &self.siblings
+    }
+
+    /// Computes the root the proof leads to from `item`.
+    ///
+    /// # Errors
+    ///
+    /// Fails only if hashing fails.
+    pub fn root(&self, item: &Hash) -> Result<Hash, HashError> {
+        let mut hash = leaf_hash(item)?;
+        let mut siblings = self.siblings.iter();
+        let mut index = self.index;
+        let mut last = self.leaf_count - 1;
+
+        while last > 0 {
+            // A node without a partner is promoted, and consumes no sibling.
+            if index % 2 == 1 || index != last {
+                let Some(sibling) = siblings.next() else {
+                    break;
+                };
+
+                hash = match index % 2 {
+                    0 => node_hash(&hash, sibling)?,
+                    _ => node_hash(sibling, &hash)?,
+                };
+            }
+
+            index >>= 1;
+            last >>= 1;
+        }
+
+        Ok(hash)
+    }
+
+    /// Checks that `item` is in the tree with the given `root`, at the
+    /// proof's index.
+    ///
+    /// # Errors
+    ///
+    /// - [`MerkleError::RootMismatch`] is returned if the proof does not lead
+    ///   from `item` to `root`.
+    /// - [`MerkleError::HashError`] is returned if hashing fails.
+    pub fn verify(&self, item: &Hash, root: &Hash) -> Result<(), MerkleError> {
+        if self.root(item)? != *root {
+            return Err(MerkleError::RootMismatch);
+        }
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.siblings.len() * HASH_SIZE_BIN);
+
+        bytes.extend_from_slice(&self.index.to_le_bytes());
+        bytes.extend_from_slice(&self.leaf_count.to_le_bytes());
+
+        for sibling in &self.siblings {
+            bytes.extend_from_slice(sibling.as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decodes a proof encoded by [`MerkleProof::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// - [`MerkleError::InvalidEncoding`] is returned if the length of
+    ///   `bytes` is not a header plus whole hashes.
+    /// - [`MerkleError::InvalidHash`] is returned if a sibling is invalid or
+    ///   needs repair.
+    /// - Anything [`MerkleProof::new`] returns.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError> {
+        let (index, rest) = bytes
+            .split_first_chunk::<8>()
+            .ok_or(MerkleError::InvalidEncoding)?;
+        let (leaf_count, siblings) = rest
+            .split_first_chunk::<8>()
+            .ok_or(MerkleError::InvalidEncoding)?;
+
+        if !siblings.len().is_multiple_of(HASH_SIZE_BIN) {
+            return Err(MerkleError::InvalidEncoding);
+        }
+
+        let siblings = siblings
+            .chunks_exact(HASH_SIZE_BIN)
+            .map(|bytes| {
+                let hash = Hash::validate(bytes)?;
+
+                if hash.as_bytes() != bytes {
+                    return Err(HashValidationError::NonCanonical);
+                }
+
+                Ok(hash)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Self::new(
+            u64::from_le_bytes(*index),
+            u64::from_le_bytes(*leaf_count),
+            siblings,
+        )
+    }
+}
+
+impl fmt::Display for MerkleProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.leaf_count)?;
+
+        for sibling in &self.siblings {
+            write!(f, ":{sibling}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for MerkleProof {
+    type Err = MerkleError;
+
+    /// Parses the text form written by [`Display`](fmt::Display).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+
+        let (index, leaf_count) = parts
+            .next()
+            .and_then(|position| position.split_once('/'))
+            .ok_or(MerkleError::InvalidEncoding)?;
+
+        // Reject signs, whitespace and leading zeros, so that the text form
+        // is unique.
+        let number = |text: &str| {
+            let digits = !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+
+            if !digits || (text.len() > 1 && text.starts_with('0')) {
+                return Err(MerkleError::InvalidEncoding);
+            }
+
+            text.parse::<u64>()
+                .map_err(|_| MerkleError::InvalidEncoding)
+        };
+
+        let siblings = parts.map(Hash::parse_canonical).collect::<Result<_, _>>()?;
+
+        Self::new(number(index)?, number(leaf_count)?, siblings)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::MerkleProof;
+    use crate::{merkle::MerkleTree, Hash, HashValidationError, MerkleError};
+
+    fn items(count: usize) -> Vec<Hash> {
+        (0..count)
+            .map(|i| Hash::hash(format!("item {i}")).expect("hashing should succeed"))
+            .collect()
+    }
+
+    fn proof(count: usize, index: usize) -> (Vec<Hash>, MerkleTree, MerkleProof) {
+        let items = items(count);
+        let tree = MerkleTree::new(&items).expect("building the tree should succeed");
+        let proof = tree.proof(index).expect("the index should be in range");
+
+        (items, tree, proof)
+    }
+
+    #[test]
+    fn rejects_wrong_items_positions_and_roots() {
+        let (items, tree, proof) = proof(6, 2);
+
+        assert_eq!(
+            proof.verify(&items[3], &tree.root()),
+            Err(MerkleError::RootMismatch)
+        );
+        assert_eq!(
+            proof.verify(&items[2], &items[2]),
+            Err(MerkleError::RootMismatch)
+        );
+
+        let moved = MerkleProof::new(3, 6, proof.siblings().to_vec())
+            .expect("the path length should match");
+
+        assert_eq!(
+            moved.verify(&items[2], &tree.root()),
+            Err(MerkleError::RootMismatch)
+        );
+    }
+
+    #[test]
+    fn new_checks_the_path_length() {
+        let (_, _, proof) = proof(5, 4);
+
+        assert_eq!(proof.siblings().len(), 1);
+        assert_eq!(
+            MerkleProof::new(4, 5, Vec::new()),
+            Err(MerkleError::InvalidPathLength {
+                expected: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            MerkleProof::new(5, 5, proof.siblings().to_vec()),
+            Err(MerkleError::IndexOutOfRange {
+                index: 5,
+                leaf_count: 5
+            })
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let (_, _, proof) = proof(11, 7);
+        let bytes = proof.to_bytes();
+
+        assert_eq!(bytes.len(), 16 + proof.siblings().len() * 48);
+        assert_eq!(&bytes[..8], &7u64.to_le_bytes());
+        assert_eq!(&bytes[8..16], &11u64.to_le_bytes());
+        assert_eq!(
+            MerkleProof::from_bytes(&bytes).expect("decoding should succeed"),
+            proof
+        );
+    }
+
+    #[test]
+    fn from_bytes_rejects_malformed_input() {
+        let (_, _, proof) = proof(4, 1);
+        let bytes = proof.to_bytes();
+
+        assert_eq!(
+            MerkleProof::from_bytes(&bytes[..15]),
+            Err(MerkleError::InvalidEncoding)
+        );
+        assert_eq!(
+            MerkleProof::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(MerkleError::InvalidEncoding)
+        );
+
+        let mut corrupted = bytes;
+
+        corrupted[20] ^= 1;
+
+        assert_eq!(
+            MerkleProof::from_bytes(&corrupted),
+            Err(MerkleError::InvalidHash(HashValidationError::NonCanonical))
+        );
+    }
+
+    #[test]
+    fn text_round_trips() {
+        let (_, _, proof) = proof(3, 2);
+        let text = proof.to_string();
+
+        assert!(text.starts_with("2/3:"));
+        assert_eq!(text.len(), 4 + 77);
+        assert_eq!(
+            text.parse::<MerkleProof>().expect("parsing should succeed"),
+            proof
+        );
+    }
+
+    #[test]
+    fn single_leaf_proof_has_no_siblings() {
+        let (items, tree, proof) = proof(1, 0);
+
+        assert_eq!(proof.to_string(), "0/1");
+        assert_eq!(
+            "0/1"
+                .parse::<MerkleProof>()
+                .expect("parsing should succeed"),
+            proof
+        );
+        assert_eq!(proof.verify(&items[0], &tree.root()), Ok(()));
+    }
+
+    #[test]
+    fn from_str_rejects_malformed_input() {
+        let (_, _, proof) = proof(3, 2);
+        let text = proof.to_string();
+
+        for malformed in [
+            "",
+            "2",
+            "+2/3",
+            "2/3:",
+            &text.to_lowercase(),
+            &text[..text.len() - 1],
+        ] {
+            assert!(
+                malformed.parse::<MerkleProof>().is_err(),
+                "{malformed:?} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{Hash, MerkleError};
+
+use super::{leaf_hash, node_hash, MerkleProof};
+
+/// A binary Merkle tree over a non-empty list of hashes.
+///
+/// Every level is kept, so proofs are read off the tree rather than
+/// recomputed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// The leaf hashes first, the root last.
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    /// Builds the tree over `items`, in order.
+    ///
+    /// # Errors
+    ///
+    /// - [`MerkleError::EmptyTree`] is returned if `items` is empty.
+    /// - [`MerkleError::HashError`] is returned if hashing fails.
+    pub fn new(items: &[Hash]) -> Result<Self, MerkleError> {
+        if items.is_empty() {
+            return Err(MerkleError::EmptyTree);
+        }
+
+        let leaves = items.iter().map(leaf_hash).collect::<Result<Vec<_>, _>>()?;
+        let mut levels = vec![leaves];
+
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let parents = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    _ => Ok(pair[0]),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            levels.push(parents);
+        }
+
+        Ok(Self { levels })
+    }
+
+    /// Returns the root hash, which commits to every item and its position.
+    #[must_use]
+    pub fn root(&self) -> Hash {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the number of items the tree was built over.
+    #[must_use]
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns the proof that the item at `index` is in the tree.
+    ///
+    /// # Errors
+    ///
+    /// [`MerkleError::IndexOutOfRange`] is returned if there is no such item.
+    pub fn proof(&self, index: usize) -> Result<MerkleProof, MerkleError> {
+        let leaf_count = self.leaf_count();
+
+        if index >= leaf_count {
+            return Err(MerkleError::IndexOutOfRange {
+                index: index as u64,
+                leaf_count: leaf_count as u64,
+            });
+        }
+
+        let mut position = index;
+        let mut siblings = Vec::with_capacity(self.levels.len() - 1);
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(*sibling);
+            }
+
+            position >>= 1;
+        }
+
+        MerkleProof::new(index as u64, leaf_count as u64, siblings)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::MerkleTree;
+    use crate::{
+        merkle::{leaf_hash, node_hash},
+        Hash, MerkleError,
+    };
+
+    fn items(count: usize) -> Vec<Hash> {
+        (0..count)
+            .map(|i| Hash::hash(i.to_le_bytes()).expect("hashing should succeed"))
+            .collect()
+    }
+
+    fn tree(items: &[Hash]) -> MerkleTree {
+        MerkleTree::new(items).expect("building the tree should succeed")
+    }
+
+    #[test]
+    fn rejects_an_empty_list() {
+        assert_eq!(MerkleTree::new(&[]), Err(MerkleError::EmptyTree));
+    }
+
+    #[test]
+    fn single_leaf_root_is_the_leaf_hash() {
+        let items = items(1);
+
+        assert_eq!(
+            tree(&items).root(),
+            leaf_hash(&items[0]).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn odd_nodes_are_promoted() {
+        let items = items(3);
+        let leaves: Vec<_> = items
+            .iter()
+            .map(|item| leaf_hash(item).expect("hashing should succeed"))
+            .collect();
+
+        let left = node_hash(&leaves[0], &leaves[1]).expect("hashing should succeed");
+        let root = node_hash(&left, &leaves[2]).expect("hashing should succeed");
+
+        assert_eq!(tree(&items).root(), root);
+    }
+
+    #[test]
+    fn root_depends_on_order_and_count() {
+        let items = items(4);
+        let mut swapped = items.clone();
+
+        swapped.swap(1, 2);
+
+        assert_ne!(tree(&items).root(), tree(&swapped).root());
+        assert_ne!(tree(&items).root(), tree(&items[..3]).root());
+    }
+
+    #[test]
+    fn every_proof_verifies() {
+        for count in 1..=17 {
+            let items = items(count);
+            let tree = tree(&items);
+
+            for (index, item) in items.iter().enumerate() {
+                let proof = tree.proof(index).expect("the index should be in range");
+
+                assert_eq!(proof.verify(item, &tree.root()), Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn proof_rejects_out_of_range_indices() {
+        assert_eq!(
+            tree(&items(3)).proof(3),
+            Err(MerkleError::IndexOutOfRange {
+                index: 3,
+                leaf_count: 3
+            })
+        );
+    }
+}
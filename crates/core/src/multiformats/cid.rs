@@ -0,0 +1,192 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Hash, MultiformatError};
+
+use super::{
+    read_multihash, read_varint, write_multihash, write_varint, Multibase, CID_VERSION, RAW_CODEC,
+};
+
+/// A version 1 content identifier for a [`Hash`](struct@Hash).
+///
+/// A CID is the [`CID_VERSION`], the multicodec code of the content's
+/// format, and the content's multihash, each varint-prefixed as the CID
+/// specification lays out. [`Display`](fmt::Display) writes it in lowercase
+/// base32 multibase, and [`FromStr`] parses any supported [`Multibase`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cid {
+    codec: u64,
+    hash: Hash,
+}
+
+impl Cid {
+    /// Creates a CID for content in the format with multicodec code `codec`.
+    #[must_use]
+    pub const fn new(codec: u64, hash: Hash) -> Self {
+        Self { codec, hash }
+    }
+
+    /// Creates a CID for raw binary content.
+    #[must_use]
+    pub const fn raw(hash: Hash) -> Self {
+        Self::new(RAW_CODEC, hash)
+    }
+
+    #[must_use]
+    pub const fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    #[must_use]
+    pub const fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    /// Returns the binary form: the version, the codec and the multihash.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        write_varint(&mut bytes, CID_VERSION);
+        write_varint(&mut bytes, self.codec);
+        write_multihash(&mut bytes, &self.hash);
+
+        bytes
+    }
+
+    /// Decodes the binary form written by [`Cid::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// - [`MultiformatError::UnsupportedCidVersion`] is returned if the CID
+    ///   is not version 1.
+    /// - [`MultiformatError::InvalidEncoding`] is returned if bytes follow
+    ///   the multihash.
+    /// - Anything [`decode_multihash`](super::decode_multihash) returns.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MultiformatError> {
+        let (version, rest) = read_varint(bytes)?;
+
+        if version != CID_VERSION {
+            return Err(MultiformatError::UnsupportedCidVersion(version));
+        }
+
+        let (codec, rest) = read_varint(rest)?;
+        let (hash, rest) = read_multihash(rest)?;
+
+        if !rest.is_empty() {
+            return Err(MultiformatError::InvalidEncoding);
+        }
+
+        Ok(Self { codec, hash })
+    }
+
+    /// Returns the text form in the given multibase.
+    #[must_use]
+    pub fn encode(&self, base: Multibase) -> String {
+        base.encode(&self.to_bytes())
+    }
+}
+
+impl From<Hash> for Cid {
+    /// Creates a CID for raw binary content.
+    fn from(hash: Hash) -> Self {
+        Self::raw(hash)
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode(Multibase::Base32))
+    }
+}
+
+impl FromStr for Cid {
+    type Err = MultiformatError;
+
+    /// Parses the text form in any supported [`Multibase`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, bytes) = Multibase::decode(s)?;
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::Cid;
+    use crate::{
+        multiformats::{encode_multihash, Multibase, RAW_CODEC},
+        Hash, MultiformatError,
+    };
+
+    fn cid() -> Cid {
+        Cid::raw(Hash::hash(b"cid").expect("hashing should succeed"))
+    }
+
+    #[test]
+    fn binary_form_is_version_codec_and_multihash() {
+        let cid = cid();
+        let bytes = cid.to_bytes();
+
+        assert_eq!(&bytes[..2], &[0x01, 0x55]);
+        assert_eq!(&bytes[2..], encode_multihash(&cid.hash()));
+        assert_eq!(
+            Cid::from_bytes(&bytes).expect("decoding should succeed"),
+            cid
+        );
+    }
+
+    #[test]
+    fn text_round_trips_in_every_base() {
+        let cid = Cid::new(0x71, cid().hash());
+
+        assert!(cid.to_string().starts_with("bafy"));
+
+        for base in [Multibase::Base16, Multibase::Base32, Multibase::Base64Url] {
+            let text = cid.encode(base);
+
+            assert_eq!(text.parse::<Cid>().expect("parsing should succeed"), cid);
+        }
+
+        assert_eq!(
+            cid.to_string()
+                .to_uppercase()
+                .parse::<Cid>()
+                .expect("parsing should succeed"),
+            cid
+        );
+        assert_eq!(Cid::from(cid.hash()).codec(), RAW_CODEC);
+    }
+
+    #[test]
+    fn rejects_other_versions_and_trailing_bytes() {
+        let mut bytes = cid().to_bytes();
+
+        bytes[0] = 0x02;
+
+        assert_eq!(
+            Cid::from_bytes(&bytes),
+            Err(MultiformatError::UnsupportedCidVersion(2))
+        );
+
+        let mut bytes = cid().to_bytes();
+
+        bytes.push(0);
+
+        assert_eq!(
+            Cid::from_bytes(&bytes),
+            Err(MultiformatError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_multihashes() {
+        // A CIDv1 of raw content hashed with SHA-256.
+        let text = "bafkreigh2akiscaildcqabsyg3dfr6chu3fgpregiymsck7e7aqa4s52zy";
+
+        assert_eq!(
+            text.parse::<Cid>(),
+            Err(MultiformatError::UnsupportedCode(0x12))
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Multihash and CID encodings of [`Hash`](struct@Hash) values, for
+//! exchanging content addresses with IPFS-style tooling.
+//!
+//! A multihash is a self-describing digest: a varint code naming the hash
+//! function, a varint digest length, and the digest. ps-hash has no code in
+//! the multicodec table, so [`MULTIHASH_CODE`] is taken from its private-use
+//! range, and the "digest" is the whole [`HASH_SIZE_BIN`]-byte hash, so that
+//! the length field and parity survive the round trip.
+//!
+//! A [`Cid`] (content identifier, version 1) prefixes a multihash with the
+//! CID version and a content codec, and is written in a multibase: its
+//! encoded bytes preceded by a character naming the base. CIDs are written
+//! in lowercase base32 by default, as IPFS does.
+//!
+//! Decoding accepts only ps-hash multihashes, and only canonical, error-free
+//! hashes, so that every accepted encoding round-trips exactly.
+//!
+//! # Examples
+//!
+//! ```
+//! use ps_hash_core::{
+//!     multiformats::{decode_multihash, encode_multihash, Cid, Multibase},
+//!     Hash,
+//! };
+//!
+//! let hash = Hash::hash(b"content")?;
+//!
+//! assert_eq!(decode_multihash(&encode_multihash(&hash))?, hash);
+//!
+//! let cid = Cid::raw(hash);
+//! let text = cid.to_string();
+//!
+//! assert!(text.starts_with('b'));
+//! assert_eq!(text.parse::<Cid>()?.hash(), hash);
+//! assert_eq!(cid.encode(Multibase::Base64Url).parse::<Cid>()?, cid);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod cid;
+mod multibase;
+
+pub use cid::Cid;
+pub use multibase::Multibase;
+
+use crate::{Hash, HashValidationError, MultiformatError, HASH_SIZE_BIN};
+
+/// The multihash code of ps-hash, from the multicodec private-use range
+/// `0x300000..=0x3FFFFF`. Its low bytes spell `ps`.
+pub const MULTIHASH_CODE: u64 = 0x30_7073;
+
+/// The CID version [`Cid`] reads and writes.
+pub const CID_VERSION: u64 = 1;
+
+/// The multicodec code of raw binary content.
+pub const RAW_CODEC: u64 = 0x55;
+
+/// The longest varint the multiformats specifications allow, in bytes.
+const MAX_VARINT_LEN: usize = 9;
+
+/// Encodes `hash` as a multihash: [`MULTIHASH_CODE`], the length
+/// [`HASH_SIZE_BIN`], and the hash bytes.
+#[must_use]
+pub fn encode_multihash(hash: &Hash) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(2 * MAX_VARINT_LEN + HASH_SIZE_BIN);
+
+    write_multihash(&mut bytes, hash);
+    bytes
+}
+
+/// Decodes a multihash encoded by [`encode_multihash`].
+///
+/// # Errors
+///
+/// - [`MultiformatError::UnsupportedCode`] is returned if the multihash is
+///   not a ps-hash multihash.
+/// - [`MultiformatError::InvalidDigestLength`] is returned if the digest
+///   length is not [`HASH_SIZE_BIN`].
+/// - [`MultiformatError::InvalidEncoding`] is returned if the bytes end
+///   early or continue past the digest.
+/// - [`MultiformatError::InvalidVarint`] is returned if a varint is
+///   malformed.
+/// - [`MultiformatError::InvalidHash`] is returned if the hash is invalid or
+///   needs repair.
+pub fn decode_multihash(bytes: &[u8]) -> Result<Hash, MultiformatError> {
+    let (hash, rest) = read_multihash(bytes)?;
+
+    if !rest.is_empty() {
+        return Err(MultiformatError::InvalidEncoding);
+    }
+
+    Ok(hash)
+}
+
+fn write_multihash(out: &mut Vec<u8>, hash: &Hash) {
+    write_varint(out, MULTIHASH_CODE);
+    write_varint(out, HASH_SIZE_BIN as u64);
+    out.extend_from_slice(hash.as_bytes());
+}
+
+/// Reads a multihash from the front of `bytes`, returning the hash and the
+/// bytes after it.
+fn read_multihash(bytes: &[u8]) -> Result<(Hash, &[u8]), MultiformatError> {
+    let (code, rest) = read_varint(bytes)?;
+
+    if code != MULTIHASH_CODE {
+        return Err(MultiformatError::UnsupportedCode(code));
+    }
+
+    let (len, rest) = read_varint(rest)?;
+
+    if len != HASH_SIZE_BIN as u64 {
+        return Err(MultiformatError::InvalidDigestLength(len));
+    }
+
+    let (digest, rest) = rest
+        .split_first_chunk::<HASH_SIZE_BIN>()
+        .ok_or(MultiformatError::InvalidEncoding)?;
+
+    let hash = Hash::validate(digest)?;
+
+    if hash.as_bytes() != digest {
+        return Err(HashValidationError::NonCanonical.into());
+    }
+
+    Ok((hash, rest))
+}
+
+/// Appends `value` as an unsigned LEB128 varint.
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+
+    out.push(value as u8);
+}
+
+/// Reads a minimally encoded unsigned varint of at most [`MAX_VARINT_LEN`]
+/// bytes from the front of `bytes`.
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), MultiformatError> {
+    let mut value = 0u64;
+
+    for (index, &byte) in bytes.iter().take(MAX_VARINT_LEN).enumerate() {
+        value |= u64::from(byte & 0x7F) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            // A zero final byte after others would make the encoding overlong.
+            if byte == 0 && index > 0 {
+                return Err(MultiformatError::InvalidVarint);
+            }
+
+            return Ok((value, &bytes[index + 1..]));
+        }
+    }
+
+    Err(MultiformatError::InvalidVarint)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::{decode_multihash, encode_multihash, read_varint, write_varint, MULTIHASH_CODE};
+    use crate::{Hash, HashValidationError, MultiformatError};
+
+    fn hash(data: &[u8]) -> Hash {
+        Hash::hash(data).expect("hashing should succeed")
+    }
+
+    #[test]
+    fn varints_round_trip_and_are_minimal() {
+        for value in [0, 1, 0x7F, 0x80, 0x3FFF, MULTIHASH_CODE, u64::MAX >> 1] {
+            let mut bytes = Vec::new();
+
+            write_varint(&mut bytes, value);
+
+            assert_eq!(
+                read_varint(&bytes).expect("reading should succeed"),
+                (value, &[][..])
+            );
+        }
+
+        assert_eq!(
+            read_varint(&[0x80, 0x00]),
+            Err(MultiformatError::InvalidVarint)
+        );
+        assert_eq!(read_varint(&[0x80]), Err(MultiformatError::InvalidVarint));
+        assert_eq!(
+            read_varint(&[0xFF; 10]),
+            Err(MultiformatError::InvalidVarint)
+        );
+    }
+
+    #[test]
+    fn multihash_has_the_expected_layout() {
+        let hash = hash(b"multihash");
+        let bytes = encode_multihash(&hash);
+
+        assert_eq!(&bytes[..4], &[0xF3, 0xE0, 0xC1, 0x01]);
+        assert_eq!(bytes[4], 48);
+        assert_eq!(&bytes[5..], hash.as_bytes());
+        assert_eq!(
+            decode_multihash(&bytes).expect("decoding should succeed"),
+            hash
+        );
+    }
+
+    #[test]
+    fn decode_rejects_foreign_and_malformed_multihashes() {
+        let hash = hash(b"foreign");
+        let mut sha256 = vec![0x12, 0x20];
+
+        sha256.extend_from_slice(&hash.digest()[..]);
+
+        assert_eq!(
+            decode_multihash(&sha256),
+            Err(MultiformatError::UnsupportedCode(0x12))
+        );
+
+        let bytes = encode_multihash(&hash);
+        let mut long = bytes.clone();
+        let mut short = bytes.clone();
+        let mut corrupted = bytes.clone();
+
+        long.push(0);
+        short[4] = 32;
+        corrupted[10] ^= 1;
+
+        assert_eq!(
+            decode_multihash(&bytes[..bytes.len() - 1]),
+            Err(MultiformatError::InvalidEncoding)
+        );
+        assert_eq!(
+            decode_multihash(&long),
+            Err(MultiformatError::InvalidEncoding)
+        );
+        assert_eq!(
+            decode_multihash(&short),
+            Err(MultiformatError::InvalidDigestLength(32))
+        );
+        assert_eq!(
+            decode_multihash(&corrupted),
+            Err(MultiformatError::InvalidHash(
+                HashValidationError::NonCanonical
+            ))
+        );
+    }
+}
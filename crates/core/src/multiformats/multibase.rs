@@ -0,0 +1,217 @@
+use crate::MultiformatError;
+
+const BASE16_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The RFC 4648 base32 alphabet, lowercased.
+const BASE32_DIGITS: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// A multibase a [`Cid`](super::Cid) can be written in.
+///
+/// Each is unpadded and named by its multibase prefix character. Parsing
+/// also accepts the uppercase forms of base16 (`F`) and base32 (`B`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Multibase {
+    /// Lowercase hexadecimal, prefixed with `f`.
+    Base16,
+    /// Lowercase RFC 4648 base32, prefixed with `b`: the default for CIDv1.
+    #[default]
+    Base32,
+    /// Unpadded base64url, prefixed with `u`.
+    Base64Url,
+}
+
+impl Multibase {
+    /// Returns the character that names this base.
+    #[must_use]
+    pub const fn prefix(self) -> char {
+        match self {
+            Self::Base16 => 'f',
+            Self::Base32 => 'b',
+            Self::Base64Url => 'u',
+        }
+    }
+
+    /// Encodes `bytes` in this base, prefix included.
+    #[must_use]
+    pub fn encode(self, bytes: &[u8]) -> String {
+        let mut text = String::from(self.prefix());
+
+        match self {
+            Self::Base16 => encode_bits(&mut text, bytes, 4, BASE16_DIGITS),
+            Self::Base32 => encode_bits(&mut text, bytes, 5, BASE32_DIGITS),
+            Self::Base64Url => text.push_str(&ps_base64::encode(bytes)),
+        }
+
+        text
+    }
+
+    /// Decodes a string written by [`Multibase::encode`] in any base,
+    /// selected by its prefix.
+    ///
+    /// Only the canonical encoding is accepted: no padding, whitespace or
+    /// mixed case, and no stray bits in a trailing character.
+    ///
+    /// # Errors
+    ///
+    /// - [`MultiformatError::UnsupportedMultibase`] is returned if the
+    ///   prefix names no supported base.
+    /// - [`MultiformatError::InvalidEncoding`] is returned if `text` is empty
+    ///   or its payload is not canonical in its base.
+    pub fn decode(text: &str) -> Result<(Self, Vec<u8>), MultiformatError> {
+        let mut chars = text.chars();
+        let prefix = chars.next().ok_or(MultiformatError::InvalidEncoding)?;
+        let payload = chars.as_str();
+
+        let (base, canonical) = match prefix {
+            'f' => (Self::Base16, payload.to_owned()),
+            'F' => (Self::Base16, uppercase_only(payload)?),
+            'b' => (Self::Base32, payload.to_owned()),
+            'B' => (Self::Base32, uppercase_only(payload)?),
+            'u' => (Self::Base64Url, payload.to_owned()),
+            _ => return Err(MultiformatError::UnsupportedMultibase(prefix)),
+        };
+
+        let bytes = match base {
+            Self::Base16 => decode_bits(&canonical, 4, BASE16_DIGITS)?,
+            Self::Base32 => decode_bits(&canonical, 5, BASE32_DIGITS)?,
+            Self::Base64Url => ps_base64::decode(canonical.as_bytes()),
+        };
+
+        // Decoding is lenient about trailing bits and, for base64url, about
+        // the alphabet, so only an exact re-encoding proves canonical input.
+        if base.encode(&bytes)[1..] != canonical {
+            return Err(MultiformatError::InvalidEncoding);
+        }
+
+        Ok((base, bytes))
+    }
+}
+
+/// Lowercases `payload`, which must not already contain lowercase letters.
+fn uppercase_only(payload: &str) -> Result<String, MultiformatError> {
+    if payload.bytes().any(|byte| byte.is_ascii_lowercase()) {
+        return Err(MultiformatError::InvalidEncoding);
+    }
+
+    Ok(payload.to_ascii_lowercase())
+}
+
+/// Appends `bytes` as an MSB-first stream of `bits`-bit digits, zero-padding
+/// the last one.
+fn encode_bits(text: &mut String, bytes: &[u8], bits: u32, digits: &[u8]) {
+    let mask = (1u32 << bits) - 1;
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        buffered += 8;
+
+        while buffered >= bits {
+            buffered -= bits;
+            text.push(char::from(digits[((buffer >> buffered) & mask) as usize]));
+        }
+    }
+
+    if buffered > 0 {
+        text.push(char::from(
+            digits[((buffer << (bits - buffered)) & mask) as usize],
+        ));
+    }
+}
+
+/// Decodes an MSB-first stream of `bits`-bit digits, dropping the bits of a
+/// trailing partial byte.
+fn decode_bits(text: &str, bits: u32, digits: &[u8]) -> Result<Vec<u8>, MultiformatError> {
+    let mut bytes = Vec::with_capacity(text.len() * bits as usize / 8);
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+
+    for byte in text.bytes() {
+        let value = digits
+            .iter()
+            .position(|&digit| digit == byte)
+            .ok_or(MultiformatError::InvalidEncoding)?;
+
+        buffer = (buffer << bits) | value as u32;
+        buffered += bits;
+
+        if buffered >= 8 {
+            buffered -= 8;
+            bytes.push((buffer >> buffered) as u8);
+        }
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::Multibase;
+    use crate::MultiformatError;
+
+    #[test]
+    fn matches_rfc_4648_test_vectors() {
+        assert_eq!(Multibase::Base32.encode(b"foobar"), "bmzxw6ytboi");
+        assert_eq!(Multibase::Base32.encode(b"f"), "bmy");
+        assert_eq!(Multibase::Base16.encode(b"foobar"), "f666f6f626172");
+        assert_eq!(Multibase::Base64Url.encode(b"foobar"), "uZm9vYmFy");
+    }
+
+    #[test]
+    fn round_trips_in_every_base() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+
+        for base in [Multibase::Base16, Multibase::Base32, Multibase::Base64Url] {
+            for len in [0, 1, 2, 3, 4, 5, 6, 255] {
+                let text = base.encode(&bytes[..len]);
+
+                assert_eq!(
+                    Multibase::decode(&text).expect("decoding should succeed"),
+                    (base, bytes[..len].to_vec()),
+                    "{text}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn accepts_uppercase_prefixes() {
+        assert_eq!(
+            Multibase::decode("BMZXW6YTBOI").expect("decoding should succeed"),
+            (Multibase::Base32, b"foobar".to_vec())
+        );
+        assert_eq!(
+            Multibase::decode("F666F6F").expect("decoding should succeed"),
+            (Multibase::Base16, b"foo".to_vec())
+        );
+    }
+
+    #[test]
+    fn rejects_non_canonical_payloads() {
+        for text in [
+            "",
+            "bmz=",
+            "bMZ",
+            "Bmz",
+            "bmz",
+            "f6",
+            "f6g",
+            "uZm9+",
+            "uZm9vYmFy==",
+        ] {
+            assert_eq!(
+                Multibase::decode(text),
+                Err(MultiformatError::InvalidEncoding),
+                "{text:?}"
+            );
+        }
+
+        assert_eq!(
+            Multibase::decode("z2drjgbQfz"),
+            Err(MultiformatError::UnsupportedMultibase('z'))
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use std::cmp::Ordering;
+
+use crate::{
+    encoding::{base64, crockford, hex},
+    Hash, PrefixError, Representation, HASH_SIZE_BIN,
+};
+
+/// A sorted set of hashes, searchable by abbreviated prefixes.
+///
+/// Like Git's short object names, a hash can be referred to by any prefix of
+/// its Crockford Base32 or base64url form that no other hash in the set
+/// shares. [`PrefixIndex::abbreviate`] produces such prefixes, and
+/// [`PrefixIndex::resolve`] looks them up again.
+///
+/// Prefixes are decoded with the same lenient rules as
+/// [`Hash::validate`]: a Crockford prefix is case-insensitive and accepts the
+/// ambiguous glyphs `I`, `L`, `O`, and `U`, and a base64url prefix also
+/// accepts the standard alphabet. Since both encodings write the bits of a
+/// hash in order, the hashes sharing a prefix are adjacent in the sorted
+/// set, and a lookup is a binary search.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_core::{Hash, PrefixIndex, Representation};
+///
+/// let index: PrefixIndex = ["one", "two", "three"]
+///     .iter()
+///     .map(Hash::hash)
+///     .collect::<Result<_, _>>()?;
+///
+/// let two = Hash::hash("two")?;
+/// let short = index.abbreviate(&two, 4);
+///
+/// assert_eq!(short.len(), 4);
+/// assert_eq!(index.resolve(short.to_lowercase(), Representation::Crockford)?, two);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrefixIndex {
+    hashes: Vec<Hash>,
+}
+
+/// The leading bits of a hash, decoded from a prefix of its representation.
+struct BitPrefix {
+    bytes: [u8; HASH_SIZE_BIN],
+    bits: usize,
+}
+
+impl PrefixIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `hash`, returning whether it was not already present.
+    pub fn insert(&mut self, hash: Hash) -> bool {
+        match self.hashes.binary_search(&hash) {
+            Ok(_) => false,
+            Err(position) => {
+                self.hashes.insert(position, hash);
+                true
+            }
+        }
+    }
+
+    /// Removes `hash`, returning whether it was present.
+    pub fn remove(&mut self, hash: &Hash) -> bool {
+        match self.hashes.binary_search(hash) {
+            Ok(position) => {
+                self.hashes.remove(position);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, hash: &Hash) -> bool {
+        self.hashes.binary_search(hash).is_ok()
+    }
+
+    /// Returns every hash in the set, in ascending order.
+    #[must_use]
+    pub fn hashes(&self) -> &[Hash] {
+        &self.hashes
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Returns every hash whose `representation` starts with `prefix`, in
+    /// ascending order.
+    ///
+    /// A prefix longer than a whole hash matches nothing, and an empty prefix
+    /// matches everything.
+    pub fn matches(&self, prefix: impl AsRef<[u8]>, representation: Representation) -> &[Hash] {
+        let Some(prefix) = BitPrefix::decode(prefix.as_ref(), representation) else {
+            return &[];
+        };
+
+        let start = self
+            .hashes
+            .partition_point(|hash| prefix.cmp(hash) == Ordering::Less);
+        let end = self
+            .hashes
+            .partition_point(|hash| prefix.cmp(hash) != Ordering::Greater);
+
+        &self.hashes[start..end]
+    }
+
+    /// Returns the only hash whose `representation` starts with `prefix`.
+    ///
+    /// # Errors
+    ///
+    /// - [`PrefixError::NotFound`] is returned if no hash matches.
+    /// - [`PrefixError::Ambiguous`] is returned if several hashes match;
+    ///   [`PrefixIndex::matches`] lists them.
+    pub fn resolve(
+        &self,
+        prefix: impl AsRef<[u8]>,
+        representation: Representation,
+    ) -> Result<Hash, PrefixError> {
+        match self.matches(prefix, representation) {
+            [] => Err(PrefixError::NotFound),
+            [hash] => Ok(*hash),
+            matches => Err(PrefixError::Ambiguous(matches.len())),
+        }
+    }
+
+    /// Returns the length of the shortest prefix of `hash`'s
+    /// `representation` that no other hash in the set shares.
+    ///
+    /// `hash` need not be in the set. The length is at least 1, and at most
+    /// the length of the whole representation.
+    #[must_use]
+    pub fn unique_prefix_len(&self, hash: &Hash, representation: Representation) -> usize {
+        let position = self.hashes.partition_point(|other| other < hash);
+        let after = match self.hashes.get(position) {
+            Some(other) if other == hash => position + 1,
+            _ => position,
+        };
+
+        let shared = [position.checked_sub(1), Some(after)]
+            .into_iter()
+            .flatten()
+            .filter_map(|neighbour| self.hashes.get(neighbour))
+            .map(|neighbour| shared_bits(hash, neighbour))
+            .max()
+            .unwrap_or(0);
+
+        (shared + 1)
+            .div_ceil(representation.bits_per_symbol())
+            .min(representation.full_size())
+    }
+
+    /// Returns the shortest unique prefix of `hash`'s Crockford Base32 form,
+    /// but at least `min_len` characters of it.
+    #[must_use]
+    pub fn abbreviate(&self, hash: &Hash, min_len: usize) -> String {
+        let mut crockford = hash.to_crockford();
+        let len = self
+            .unique_prefix_len(hash, Representation::Crockford)
+            .max(min_len);
+
+        crockford.truncate(len);
+        crockford
+    }
+}
+
+impl FromIterator<Hash> for PrefixIndex {
+    fn from_iter<I: IntoIterator<Item = Hash>>(iter: I) -> Self {
+        let mut hashes: Vec<Hash> = iter.into_iter().collect();
+
+        hashes.sort_unstable();
+        hashes.dedup();
+
+        Self { hashes }
+    }
+}
+
+impl Extend<Hash> for PrefixIndex {
+    fn extend<I: IntoIterator<Item = Hash>>(&mut self, iter: I) {
+        self.hashes.extend(iter);
+        self.hashes.sort_unstable();
+        self.hashes.dedup();
+    }
+}
+
+impl BitPrefix {
+    /// Decodes `prefix`, or returns `None` if it is longer than a whole hash.
+    fn decode(prefix: &[u8], representation: Representation) -> Option<Self> {
+        let symbols = representation.symbol_positions(prefix).len();
+
+        if symbols > representation.full_size() {
+            return None;
+        }
+
+        let bytes = match representation {
+            Representation::Binary | Representation::Compact => {
+                let mut bytes = [0; HASH_SIZE_BIN];
+
+                bytes[..prefix.len()].copy_from_slice(prefix);
+                bytes
+            }
+            // Zero symbols complete the prefix to a whole hash, which the
+            // decoders fill in exactly; partial symbol groups they do not.
+            Representation::Base64 => {
+                base64::decode(&padded(prefix, symbols, b'A', representation))
+            }
+            Representation::Crockford => {
+                crockford::decode(&padded(prefix, symbols, b'0', representation))
+            }
+            Representation::Hex => hex::decode(&padded(prefix, symbols, b'0', representation)),
+        };
+
+        Some(Self {
+            bytes,
+            // The last symbol of a whole hash may carry padding bits.
+            bits: (symbols * representation.bits_per_symbol()).min(HASH_SIZE_BIN * 8),
+        })
+    }
+
+    /// Compares the leading bits of `hash` with the prefix.
+    fn cmp(&self, hash: &Hash) -> Ordering {
+        let whole = self.bits / 8;
+        let rest = self.bits % 8;
+
+        hash.inner[..whole]
+            .cmp(&self.bytes[..whole])
+            .then_with(|| match rest {
+                0 => Ordering::Equal,
+                rest => {
+                    let mask = 0xFF << (8 - rest);
+
+                    (hash.inner[whole] & mask).cmp(&(self.bytes[whole] & mask))
+                }
+            })
+    }
+}
+
+/// Appends `zero` to `prefix` until it has a whole hash's worth of symbols.
+fn padded(prefix: &[u8], symbols: usize, zero: u8, representation: Representation) -> Vec<u8> {
+    let mut padded = prefix.to_vec();
+
+    padded.resize(prefix.len() + representation.full_size() - symbols, zero);
+    padded
+}
+
+/// Returns the number of leading bits `a` and `b` have in common.
+fn shared_bits(a: &Hash, b: &Hash) -> usize {
+    a.inner
+        .iter()
+        .zip(&b.inner)
+        .position(|(a, b)| a != b)
+        .map_or(HASH_SIZE_BIN * 8, |index| {
+            index * 8 + (a.inner[index] ^ b.inner[index]).leading_zeros() as usize
+        })
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::PrefixIndex;
+    use crate::{Hash, PrefixError, Representation};
+
+    fn index(count: u32) -> PrefixIndex {
+        (0..count)
+            .map(|i| Hash::hash(i.to_le_bytes()).expect("hashing should succeed"))
+            .collect()
+    }
+
+    #[test]
+    fn matches_agree_with_string_prefixes() {
+        let index = index(500);
+
+        for hash in index.hashes().iter().step_by(37) {
+            for (encoded, representation) in [
+                (hash.to_crockford(), Representation::Crockford),
+                (hash.to_base64(), Representation::Base64),
+                (hash.to_hex(), Representation::Hex),
+            ] {
+                for len in [0, 1, 2, 3, 5, 8, 13, encoded.len()] {
+                    let prefix = &encoded[..len];
+                    let expected: Vec<_> = index
+                        .hashes()
+                        .iter()
+                        .filter(|other| match representation {
+                            Representation::Crockford => other.to_crockford().starts_with(prefix),
+                            Representation::Hex => other.to_hex().starts_with(prefix),
+                            _ => other.to_base64().starts_with(prefix),
+                        })
+                        .copied()
+                        .collect();
+
+                    assert_eq!(index.matches(prefix, representation), expected, "{prefix}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crockford_prefixes_are_lenient() {
+        let index = index(100);
+        let hash = index.hashes()[42];
+        let len = index.unique_prefix_len(&hash, Representation::Crockford) + 2;
+        let prefix = hash.to_crockford()[..len]
+            .to_lowercase()
+            .replace('0', "o")
+            .replace('1', "l");
+
+        assert_eq!(index.resolve(prefix, Representation::Crockford), Ok(hash));
+    }
+
+    #[test]
+    fn binary_prefixes_match_leading_bytes() {
+        let index = index(100);
+        let hash = index.hashes()[7];
+
+        assert_eq!(
+            index.resolve(&hash.as_bytes()[..4], Representation::Binary),
+            Ok(hash)
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_ambiguous_prefixes() {
+        let index = index(100);
+
+        assert_eq!(
+            index.resolve("", Representation::Crockford),
+            Err(PrefixError::Ambiguous(100))
+        );
+        assert_eq!(
+            PrefixIndex::new().resolve("ABC", Representation::Crockford),
+            Err(PrefixError::NotFound)
+        );
+
+        let hash = index.hashes()[0];
+        let too_long = format!("{}0", hash.to_crockford());
+
+        assert_eq!(
+            index.resolve(too_long, Representation::Crockford),
+            Err(PrefixError::NotFound)
+        );
+    }
+
+    #[test]
+    fn unique_prefixes_are_shortest() {
+        let index = index(300);
+
+        for hash in index.hashes() {
+            for (encoded, representation) in [
+                (hash.to_crockford(), Representation::Crockford),
+                (hash.to_base64(), Representation::Base64),
+            ] {
+                let len = index.unique_prefix_len(hash, representation);
+
+                assert_eq!(index.resolve(&encoded[..len], representation), Ok(*hash));
+                assert!(
+                    len == 1 || index.resolve(&encoded[..len - 1], representation).is_err(),
+                    "a prefix of {len} characters should be the shortest"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn abbreviate_respects_the_minimum_length() {
+        let index = index(3);
+        let hash = index.hashes()[1];
+
+        assert_eq!(index.abbreviate(&hash, 7), hash.to_crockford()[..7]);
+        assert_eq!(index.abbreviate(&hash, 100), hash.to_crockford());
+    }
+
+    #[test]
+    fn insert_and_remove_keep_the_set_sorted() {
+        let mut index = PrefixIndex::new();
+        let hashes: Vec<_> = (0u8..10)
+            .map(|i| Hash::hash([i]).expect("hashing should succeed"))
+            .collect();
+
+        for hash in hashes.iter().rev() {
+            assert!(index.insert(*hash));
+        }
+
+        assert!(!index.insert(hashes[0]));
+        assert!(index.hashes().is_sorted());
+        assert!(index.remove(&hashes[3]));
+        assert!(!index.remove(&hashes[3]));
+        assert!(!index.contains(&hashes[3]));
+        assert_eq!(index.len(), 9);
+    }
+}
@@ -0,0 +1,147 @@
+use std::ops::Range;
+
+use crate::{
+    constants::{BASE64_BITS, CROCKFORD_BITS, HEX_BITS},
+    HASH_SIZE_BASE64, HASH_SIZE_BIN, HASH_SIZE_COMPACT, HASH_SIZE_CROCKFORD, HASH_SIZE_HEX,
+};
+
+/// The representation of a hash, as detected by [`Hash::validate`] from the
+/// input length.
+///
+/// [`Hash::validate`]: crate::Hash::validate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Representation {
+    /// Raw bytes of the internal representation.
+    Binary,
+    /// The truncated binary representation of
+    /// [`HASH_SIZE_COMPACT`](crate::HASH_SIZE_COMPACT) bytes.
+    Compact,
+    /// Unpadded base64url.
+    Base64,
+    /// Crockford Base32.
+    Crockford,
+    /// Hexadecimal, in either case.
+    Hex,
+}
+
+impl Representation {
+    /// Returns the input position of every symbol the decoder consumes, in
+    /// order.
+    pub(crate) fn symbol_positions(self, bytes: &[u8]) -> Vec<usize> {
+        let accepted = |byte: u8| match self {
+            Self::Binary | Self::Compact => true,
+            Self::Base64 => !byte.is_ascii_whitespace() && byte != b'=',
+            Self::Crockford => ps_crockford32::DECODE_MAP[byte as usize] != ps_crockford32::INVALID,
+            Self::Hex => byte.is_ascii_hexdigit(),
+        };
+
+        bytes
+            .iter()
+            .enumerate()
+            .filter(|&(_, &byte)| accepted(byte))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    pub(crate) const fn bits_per_symbol(self) -> usize {
+        match self {
+            Self::Binary | Self::Compact => 8,
+            Self::Base64 => BASE64_BITS,
+            Self::Crockford => CROCKFORD_BITS,
+            Self::Hex => HEX_BITS,
+        }
+    }
+
+    /// Returns the length of an untruncated hash in this representation.
+    pub(crate) const fn full_size(self) -> usize {
+        match self {
+            Self::Binary => HASH_SIZE_BIN,
+            Self::Compact => HASH_SIZE_COMPACT,
+            Self::Base64 => HASH_SIZE_BASE64,
+            Self::Crockford => HASH_SIZE_CROCKFORD,
+            Self::Hex => HASH_SIZE_HEX,
+        }
+    }
+}
+
+/// A byte the Reed-Solomon codec corrected during validation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Correction {
+    offset: usize,
+    input: Range<usize>,
+}
+
+impl Correction {
+    pub(crate) const fn new(offset: usize, input: Range<usize>) -> Self {
+        Self { offset, input }
+    }
+
+    /// Returns the offset of the corrected byte within the internal
+    /// representation.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the range of input positions that encoded the corrected byte.
+    ///
+    /// For the textual representations, this spans every character carrying
+    /// one of the byte's bits, including any skipped characters in between.
+    /// The range is empty if the byte lay past the end of a truncated input.
+    #[must_use]
+    pub fn input(&self) -> Range<usize> {
+        self.input.clone()
+    }
+}
+
+/// Describes what [`Hash::validate_with_report`] found and repaired.
+///
+/// [`Hash::validate_with_report`]: crate::Hash::validate_with_report
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationReport {
+    representation: Representation,
+    truncated: bool,
+    corrections: Vec<Correction>,
+}
+
+impl ValidationReport {
+    pub(crate) const fn new(
+        representation: Representation,
+        truncated: bool,
+        corrections: Vec<Correction>,
+    ) -> Self {
+        Self {
+            representation,
+            truncated,
+            corrections,
+        }
+    }
+
+    #[must_use]
+    pub const fn representation(&self) -> Representation {
+        self.representation
+    }
+
+    /// Returns `true` if the input was shorter than the full size of its
+    /// representation.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the number of bytes the Reed-Solomon codec corrected,
+    /// including bytes restored after truncation.
+    ///
+    /// Validation fails once this would exceed [`PARITY`](crate::PARITY).
+    #[must_use]
+    pub fn corrected_bytes(&self) -> usize {
+        self.corrections.len()
+    }
+
+    /// Returns the corrected bytes, in ascending order of offset.
+    #[must_use]
+    pub fn corrections(&self) -> &[Correction] {
+        &self.corrections
+    }
+}
@@ -0,0 +1,62 @@
+//! Serializes a [`Hash`](struct@Hash) as its base64url string in every format.
+//!
+//! Deserialization accepts any representation [`Hash::validate`] accepts.
+
+use ::serde::{Deserializer, Serializer};
+
+use crate::Hash;
+
+use super::HashVisitor;
+
+pub fn serialize<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hash.to_base64())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+    deserializer.deserialize_str(HashVisitor::lenient())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::Hash;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        hash: Hash,
+    }
+
+    #[test]
+    fn serializes_base64_in_every_format() {
+        let record = Record {
+            hash: Hash::hash(b"base64").expect("hashing should succeed"),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).expect("serialization should succeed"),
+            format!("{{\"hash\":\"{}\"}}", record.hash.to_base64())
+        );
+
+        let encoded = bincode::serialize(&record).expect("serialization should succeed");
+
+        assert!(encoded.ends_with(record.hash.to_base64().as_bytes()));
+        assert_eq!(
+            bincode::deserialize::<Record>(&encoded).expect("deserialization should succeed"),
+            record
+        );
+    }
+
+    #[test]
+    fn deserializes_any_representation() {
+        let hash = Hash::hash(b"any").expect("hashing should succeed");
+        let json = format!("{{\"hash\":\"{hash}\"}}");
+
+        assert_eq!(
+            serde_json::from_str::<Record>(&json).expect("deserialization should succeed"),
+            Record { hash }
+        );
+    }
+}
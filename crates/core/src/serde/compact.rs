@@ -0,0 +1,63 @@
+//! Serializes a [`Hash`](struct@Hash) as its
+//! [`HASH_SIZE_COMPACT`](crate::HASH_SIZE_COMPACT) compact bytes in every
+//! format.
+//!
+//! The omitted parity bytes are restored on deserialization, which accepts
+//! any representation [`Hash::validate`] accepts.
+
+use ::serde::{Deserializer, Serializer};
+
+use crate::Hash;
+
+use super::HashVisitor;
+
+pub fn serialize<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(hash.compact())
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+    deserializer.deserialize_bytes(HashVisitor::lenient())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{Hash, HASH_SIZE_COMPACT};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        hash: Hash,
+    }
+
+    #[test]
+    fn round_trips_through_binary_formats() {
+        let record = Record {
+            hash: Hash::hash(b"compact").expect("hashing should succeed"),
+        };
+
+        let encoded = bincode::serialize(&record).expect("serialization should succeed");
+
+        assert_eq!(encoded.len(), 8 + HASH_SIZE_COMPACT);
+        assert_eq!(
+            bincode::deserialize::<Record>(&encoded).expect("deserialization should succeed"),
+            record
+        );
+    }
+
+    #[test]
+    fn round_trips_through_human_readable_formats() {
+        let record = Record {
+            hash: Hash::hash(b"compact json").expect("hashing should succeed"),
+        };
+
+        let json = serde_json::to_string(&record).expect("serialization should succeed");
+
+        assert_eq!(
+            serde_json::from_str::<Record>(&json).expect("deserialization should succeed"),
+            record
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! [`serde`] support for [`Hash`](struct@Hash).
+//!
+//! [`Hash`](struct@Hash) serializes to its canonical Crockford Base32 string
+//! in human-readable formats and to its
+//! [`HASH_SIZE_BIN`](crate::HASH_SIZE_BIN) raw bytes in binary formats. It
+//! deserializes any representation [`Hash::validate`] accepts, repairing it
+//! where necessary.
+//!
+//! The submodules override this for a single field with
+//! `#[serde(with = "...")]`:
+//!
+//! - [`base64`] serializes to the base64url string in every format.
+//! - [`compact`] serializes to the [`HASH_SIZE_COMPACT`](crate::HASH_SIZE_COMPACT)
+//!   compact bytes in every format.
+//! - [`strict`] serializes like [`Hash`](struct@Hash), but deserializes only
+//!   canonical, error-free input.
+//!
+//! # Examples
+//!
+//! ```
+//! use ps_hash_core::Hash;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     content: Hash,
+//!     #[serde(with = "ps_hash_core::serde::base64")]
+//!     thumbnail: Hash,
+//! }
+//! ```
+
+use std::fmt;
+
+use ::serde::de::{Error, SeqAccess, Visitor};
+
+use crate::{Hash, HashValidationError, HASH_SIZE_HEX};
+
+pub mod base64;
+pub mod compact;
+pub mod strict;
+
+/// Deserializes a hash from a string, bytes, or a sequence of bytes, with
+/// the given parser.
+pub(crate) struct HashVisitor(pub(crate) fn(&[u8]) -> Result<Hash, HashValidationError>);
+
+impl HashVisitor {
+    /// Accepts any representation [`Hash::validate`] accepts.
+    pub(crate) fn lenient() -> Self {
+        Self(|bytes| Hash::validate(bytes))
+    }
+}
+
+impl<'de> Visitor<'de> for HashVisitor {
+    type Value = Hash;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hash")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        (self.0)(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(HASH_SIZE_HEX);
+
+        while let Some(byte) = seq.next_element()? {
+            // Hexadecimal is the longest representation, so there is no need
+            // to buffer an arbitrarily long sequence before rejecting it.
+            if bytes.len() == HASH_SIZE_HEX {
+                return Err(A::Error::invalid_length(bytes.len() + 1, &self));
+            }
+
+            bytes.push(byte);
+        }
+
+        self.visit_bytes(&bytes)
+    }
+}
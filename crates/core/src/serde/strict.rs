@@ -0,0 +1,94 @@
+//! Serializes a [`Hash`](struct@Hash) like its [`Serialize`] impl, but
+//! deserializes only input that needs no repair.
+//!
+//! Strings must pass [`Hash::parse_canonical`], and bytes must be the full
+//! [`HASH_SIZE_BIN`] bytes of a valid hash, error-free.
+
+use ::serde::{Deserializer, Serialize, Serializer};
+
+use crate::{Hash, HashValidationError, HASH_SIZE_BIN};
+
+use super::HashVisitor;
+
+pub fn serialize<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+    hash.serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(HashVisitor(parse))
+    } else {
+        deserializer.deserialize_bytes(HashVisitor(parse))
+    }
+}
+
+fn parse(bytes: &[u8]) -> Result<Hash, HashValidationError> {
+    if bytes.len() != HASH_SIZE_BIN {
+        return Hash::parse_canonical(bytes);
+    }
+
+    let hash = Hash::validate(bytes)?;
+
+    if hash.inner != bytes {
+        return Err(HashValidationError::NonCanonical);
+    }
+
+    Ok(hash)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::Hash;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        hash: Hash,
+    }
+
+    #[test]
+    fn round_trips_canonical_input() {
+        let record = Record {
+            hash: Hash::hash(b"strict").expect("hashing should succeed"),
+        };
+
+        let json = serde_json::to_string(&record).expect("serialization should succeed");
+        let encoded = bincode::serialize(&record).expect("serialization should succeed");
+
+        assert_eq!(
+            serde_json::from_str::<Record>(&json).expect("deserialization should succeed"),
+            record
+        );
+        assert_eq!(
+            bincode::deserialize::<Record>(&encoded).expect("deserialization should succeed"),
+            record
+        );
+    }
+
+    #[test]
+    fn rejects_strings_that_need_repair() {
+        let hash = Hash::hash(b"lowercase").expect("hashing should succeed");
+        let json = format!("{{\"hash\":\"{}\"}}", hash.to_crockford().to_lowercase());
+
+        assert!(serde_json::from_str::<Hash>(&json[8..json.len() - 1]).is_ok());
+        assert!(serde_json::from_str::<Record>(&json).is_err());
+    }
+
+    #[test]
+    fn rejects_bytes_that_need_repair() {
+        let record = Record {
+            hash: Hash::hash(b"corrupted").expect("hashing should succeed"),
+        };
+
+        let mut encoded = bincode::serialize(&record).expect("serialization should succeed");
+        let last = encoded.len() - 1;
+
+        encoded[last] ^= 1;
+
+        assert!(bincode::deserialize::<Hash>(&encoded).is_ok());
+        assert!(bincode::deserialize::<Record>(&encoded).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{self, ErrorKind, Read};
+
+use crate::{Hash, HashingReader, VerifyError};
+
+/// Forwards the bytes of an inner reader while checking them against an
+/// expected [`Hash`](struct@Hash).
+///
+/// Reading fails with [`ErrorKind::InvalidData`] as soon as more bytes have
+/// been read than the expected hash allows, and at end of input if the length
+/// or the digest does not match. The [`VerifyError`] describing the mismatch
+/// is the error's inner value.
+///
+/// A successful read to end of input guarantees that every byte read was the
+/// expected content; bytes forwarded before the final check, however, were
+/// not yet verified when they were returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+///
+/// use ps_hash_core::{Hash, VerifyingReader};
+///
+/// let expected = Hash::hash(b"trusted")?;
+/// let mut contents = Vec::new();
+///
+/// VerifyingReader::new(b"trusted".as_slice(), expected).read_to_end(&mut contents)?;
+///
+/// assert!(VerifyingReader::new(b"tampered".as_slice(), expected)
+///     .read_to_end(&mut Vec::new())
+///     .is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct VerifyingReader<R> {
+    reader: HashingReader<R>,
+    expected: Hash,
+    verified: bool,
+}
+
+impl<R> VerifyingReader<R> {
+    pub fn new(reader: R, expected: Hash) -> Self {
+        Self {
+            reader: HashingReader::new(reader),
+            expected,
+            verified: false,
+        }
+    }
+
+    pub const fn expected(&self) -> &Hash {
+        &self.expected
+    }
+
+    pub const fn get_ref(&self) -> &R {
+        self.reader.get_ref()
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+}
+
+impl<R: Read> Read for VerifyingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        let len = self.reader.hasher().len();
+
+        if read == 0 && !buf.is_empty() {
+            if !self.verified {
+                self.expected
+                    .verify_hasher(self.reader.hasher())
+                    .map_err(invalid_data)?;
+
+                self.verified = true;
+            }
+        } else if len > self.expected.data_max_len().to_usize() {
+            self.expected.verify_len(len).map_err(invalid_data)?;
+        }
+
+        Ok(read)
+    }
+}
+
+fn invalid_data(error: VerifyError) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::{self, ErrorKind, Read};
+
+    use super::VerifyingReader;
+    use crate::{Hash, VerifyError};
+
+    fn verify_error(error: &io::Error) -> &VerifyError {
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref())
+            .expect("the inner error should be a VerifyError")
+    }
+
+    #[test]
+    fn forwards_matching_content() {
+        let data = b"matching".repeat(10_000);
+        let expected = Hash::hash(&data).expect("hashing should succeed");
+        let mut reader = VerifyingReader::new(data.as_slice(), expected);
+        let mut forwarded = Vec::new();
+
+        reader
+            .read_to_end(&mut forwarded)
+            .expect("matching content should verify");
+
+        assert_eq!(forwarded, data);
+        assert_eq!(
+            reader
+                .read(&mut [0; 8])
+                .expect("reading at EOF should succeed"),
+            0
+        );
+    }
+
+    #[test]
+    fn rejects_a_digest_mismatch_at_eof() {
+        let expected = Hash::hash(b"original").expect("hashing should succeed");
+        let mut reader = VerifyingReader::new(b"tampered".as_slice(), expected);
+
+        let error = reader
+            .read_to_end(&mut Vec::new())
+            .expect_err("tampered content should fail");
+
+        assert_eq!(verify_error(&error), &VerifyError::DigestMismatch);
+    }
+
+    #[test]
+    fn rejects_short_content_at_eof() {
+        let expected = Hash::hash(b"complete").expect("hashing should succeed");
+        let mut reader = VerifyingReader::new(b"compl".as_slice(), expected);
+
+        let error = reader
+            .read_to_end(&mut Vec::new())
+            .expect_err("short content should fail");
+
+        assert!(matches!(
+            verify_error(&error),
+            VerifyError::LengthMismatch { actual: 5, .. }
+        ));
+    }
+
+    #[test]
+    fn rejects_long_content_before_eof() {
+        let expected = Hash::hash(b"short").expect("hashing should succeed");
+        let data = vec![0u8; 1_000_000];
+        let mut reader = VerifyingReader::new(data.as_slice(), expected);
+        let mut buffer = [0u8; 4096];
+
+        let error = reader
+            .read(&mut buffer)
+            .expect_err("overlong content should fail on the first read");
+
+        assert!(matches!(
+            verify_error(&error),
+            VerifyError::LengthMismatch { actual: 4096, .. }
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields, GenericParam, Ident,
+    LitInt,
+};
+
+/// Placement of one field or variant in the encoding.
+struct Placement {
+    skip: bool,
+    order: Option<u32>,
+}
+
+/// Expands `#[derive(ContentHash)]`.
+pub fn expand_content_hash(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote!(::ps_hash::ContentHash));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, encode) = expand_fields(&data.fields)?;
+
+            quote! {
+                let Self #pattern = *self;
+                #encode
+            }
+        }
+        Data::Enum(data) => {
+            let mut pins = Vec::with_capacity(data.variants.len());
+
+            for variant in &data.variants {
+                let placement = parse_placement(&variant.attrs)?;
+
+                if placement.skip {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "enum variants cannot be skipped",
+                    ));
+                }
+
+                pins.push((placement.order, variant.span()));
+            }
+
+            let arms = data
+                .variants
+                .iter()
+                .zip(order(&pins)?)
+                .map(|(variant, index)| {
+                    let ident = &variant.ident;
+                    let (pattern, encode) = expand_fields(&variant.fields)?;
+
+                    Ok(quote! {
+                        Self::#ident #pattern => {
+                            out.extend_from_slice(&#index.to_le_bytes());
+                            #encode
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            // Matching on the place rather than the reference also makes an
+            // empty enum's match exhaustive.
+            quote! {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "ContentHash cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::ps_hash::ContentHash for #name #ty_generics #where_clause {
+            fn encode_content(&self, out: &mut ::std::vec::Vec<u8>) {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns a pattern binding the encoded fields by reference, and the
+/// statements encoding them in order.
+fn expand_fields(fields: &Fields) -> syn::Result<(TokenStream, TokenStream)> {
+    let members: Vec<_> = fields.iter().collect();
+    let placements = members
+        .iter()
+        .map(|field| parse_placement(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let bindings: Vec<Ident> = (0..members.len())
+        .map(|index| format_ident!("__field{index}"))
+        .collect();
+
+    let included: Vec<_> = (0..members.len())
+        .filter(|&index| !placements[index].skip)
+        .collect();
+
+    let pins: Vec<_> = included
+        .iter()
+        .map(|&index| (placements[index].order, members[index].span()))
+        .collect();
+
+    let mut ordered: Vec<_> = order(&pins)?.into_iter().zip(&included).collect();
+
+    ordered.sort_by_key(|&(order, _)| order);
+
+    let encode = ordered.iter().map(|&(_, &index)| {
+        let binding = &bindings[index];
+
+        quote!(::ps_hash::content_hash::encode_field(out, #binding);)
+    });
+
+    let pattern = match fields {
+        Fields::Named(_) => {
+            let names = included.iter().map(|&index| {
+                let name = &members[index].ident;
+                let binding = &bindings[index];
+
+                quote!(#name: ref #binding)
+            });
+
+            quote!({ #(#names,)* .. })
+        }
+        Fields::Unnamed(_) => {
+            let patterns = (0..members.len()).map(|index| match placements[index].skip {
+                true => quote!(_),
+                false => {
+                    let binding = &bindings[index];
+
+                    quote!(ref #binding)
+                }
+            });
+
+            quote!(( #(#patterns),* ))
+        }
+        Fields::Unit => quote!(),
+    };
+
+    Ok((pattern, quote!(#(#encode)*)))
+}
+
+/// Assigns each item, given by its pinned order and span, its position in the
+/// encoding: the pinned order if every item has one, or the declaration order
+/// if none has.
+fn order(pins: &[(Option<u32>, Span)]) -> syn::Result<Vec<u32>> {
+    if pins.iter().all(|(order, _)| order.is_none()) {
+        return (0..pins.len())
+            .map(|index| {
+                u32::try_from(index)
+                    .map_err(|_| syn::Error::new(Span::call_site(), "too many items to encode"))
+            })
+            .collect();
+    }
+
+    let mut seen = BTreeSet::new();
+
+    pins.iter()
+        .map(|&(order, span)| {
+            let order = order.ok_or_else(|| {
+                syn::Error::new(
+                    span,
+                    "either every encoded item or none must have #[content_hash(order = ...)]",
+                )
+            })?;
+
+            if !seen.insert(order) {
+                return Err(syn::Error::new(
+                    span,
+                    format!("order = {order} is used more than once"),
+                ));
+            }
+
+            Ok(order)
+        })
+        .collect()
+}
+
+fn parse_placement(attrs: &[Attribute]) -> syn::Result<Placement> {
+    let mut placement = Placement {
+        skip: false,
+        order: None,
+    };
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("content_hash"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                placement.skip = true;
+
+                return Ok(());
+            }
+
+            if meta.path.is_ident("order") {
+                let order: LitInt = meta.value()?.parse()?;
+
+                placement.order = Some(order.base10_parse()?);
+
+                return Ok(());
+            }
+
+            Err(meta.error("expected `skip` or `order = N`"))
+        })?;
+
+        if placement.skip && placement.order.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "a skipped item cannot have an order",
+            ));
+        }
+    }
+
+    Ok(placement)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use syn::{parse_quote, DeriveInput};
+
+    use super::expand_content_hash;
+
+    fn error(input: &DeriveInput) -> String {
+        expand_content_hash(input)
+            .expect_err("the input should be rejected")
+            .to_string()
+    }
+
+    #[test]
+    fn bounds_type_parameters() {
+        let input: DeriveInput = parse_quote! {
+            struct Pair<A, B> { first: A, second: B }
+        };
+        let tokens = expand_content_hash(&input)
+            .expect("expansion should succeed")
+            .to_string();
+
+        assert!(tokens.contains("A : :: ps_hash :: ContentHash"));
+        assert!(tokens.contains("B : :: ps_hash :: ContentHash"));
+    }
+
+    #[test]
+    fn rejects_partial_and_duplicate_orders() {
+        assert!(error(&parse_quote! {
+            struct Partial { #[content_hash(order = 0)] a: u8, b: u8 }
+        })
+        .contains("either every encoded item or none"));
+        assert!(error(&parse_quote! {
+            struct Duplicate {
+                #[content_hash(order = 1)] a: u8,
+                #[content_hash(order = 1)] b: u8,
+            }
+        })
+        .contains("order = 1 is used more than once"));
+    }
+
+    #[test]
+    fn rejects_invalid_attributes() {
+        assert!(error(&parse_quote! {
+            struct Both { #[content_hash(skip, order = 0)] a: u8 }
+        })
+        .contains("a skipped item cannot have an order"));
+        assert!(error(&parse_quote! {
+            struct Unknown { #[content_hash(rename = "b")] a: u8 }
+        })
+        .contains("expected `skip` or `order = N`"));
+        assert!(error(&parse_quote! {
+            enum Skipped { #[content_hash(skip)] A }
+        })
+        .contains("enum variants cannot be skipped"));
+        assert!(error(&parse_quote! {
+            union Bits { a: u8, b: i8 }
+        })
+        .contains("cannot be derived for unions"));
+    }
+}
@@ -1,3 +1,4 @@
+use ps_hash_core::{encoding::base64, HASH_SIZE_BIN};
 use quote::quote;
 use syn::Expr;
 
@@ -15,12 +16,40 @@ pub fn expand_hash(expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
     Ok(quote!(#hash_string))
 }
 
+pub fn expand_hash_base64(expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    let encoded = base64::encode(&hash_inner(expr)?);
+
+    let hash_string = std::str::from_utf8(&encoded)
+        .map_err(|error| syn::Error::new_spanned(expr, error.to_string()))?;
+
+    Ok(quote!(#hash_string))
+}
+
+pub fn expand_hash_bytes(expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    let inner = hash_inner(expr)?;
+
+    Ok(quote!([#(#inner),*]))
+}
+
+pub fn expand_hash_value(expr: &Expr) -> syn::Result<proc_macro2::TokenStream> {
+    let bytes = expand_hash_bytes(expr)?;
+
+    Ok(quote!(::ps_hash::Hash::from_inner_unchecked(#bytes)))
+}
+
+fn hash_inner(expr: &Expr) -> syn::Result<[u8; HASH_SIZE_BIN]> {
+    let bytes = parse_bytes(expr)?;
+
+    ps_hash_core::hash_inner(&bytes)
+        .map_err(|error| syn::Error::new_spanned(expr, error.to_string()))
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
     use syn::parse_quote;
 
-    use super::expand_hash;
+    use super::{expand_hash, expand_hash_base64, expand_hash_bytes, expand_hash_value};
 
     #[test]
     fn expands_to_expected_literal() {
@@ -39,5 +68,46 @@ mod tests {
     fn reports_error_for_unsupported_input() {
         let expr = parse_quote!(non_literal);
         assert!(expand_hash(&expr).is_err());
+        assert!(expand_hash_base64(&expr).is_err());
+        assert!(expand_hash_bytes(&expr).is_err());
+        assert!(expand_hash_value(&expr).is_err());
+    }
+
+    #[test]
+    fn expands_to_expected_base64_literal() {
+        let expr = parse_quote!("base64");
+        let tokens = expand_hash_base64(&expr).expect("expansion should succeed");
+        let literal: syn::LitStr = syn::parse2(tokens).expect("output should be a string literal");
+
+        let expected = ps_hash_core::Hash::hash(b"base64").expect("hashing should succeed");
+
+        assert_eq!(literal.value(), expected.to_base64());
+    }
+
+    #[test]
+    fn expands_to_expected_byte_array() {
+        let expr = parse_quote!(b"bytes");
+        let tokens = expand_hash_bytes(&expr).expect("expansion should succeed");
+        let array: syn::ExprArray = syn::parse2(tokens).expect("output should be an array");
+
+        let bytes = crate::input::parse_bytes(&syn::Expr::Array(array))
+            .expect("the array should contain byte literals");
+        let expected = ps_hash_core::hash_inner(b"bytes").expect("hash_inner should succeed");
+
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn expands_to_a_const_constructor_call() {
+        let expr = parse_quote!("value");
+        let tokens = expand_hash_value(&expr).expect("expansion should succeed");
+        let call: syn::ExprCall = syn::parse2(tokens).expect("output should be a call");
+        let func = &call.func;
+
+        assert_eq!(
+            quote::quote!(#func).to_string(),
+            quote::quote!(::ps_hash::Hash::from_inner_unchecked).to_string()
+        );
+        assert_eq!(call.args.len(), 1);
     }
 }
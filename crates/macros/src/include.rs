@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use quote::quote;
+use syn::LitStr;
+
+/// Hashes the file at `path`, relative to the calling crate's manifest
+/// directory, and expands to its Crockford Base32 hash.
+///
+/// The expansion also includes the file with `include_bytes!`, which is
+/// discarded but makes Cargo rebuild the caller whenever the file changes.
+pub fn expand_include_hash(path: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let resolved = resolve(&path.value());
+
+    let contents = std::fs::read(&resolved).map_err(|error| {
+        syn::Error::new_spanned(
+            path,
+            format!("cannot read `{}`: {error}", resolved.display()),
+        )
+    })?;
+
+    let hash = ps_hash_core::hash_encoded(&contents)
+        .map_err(|error| syn::Error::new_spanned(path, error.to_string()))?;
+
+    let hash_string = std::str::from_utf8(&hash)
+        .map_err(|error| syn::Error::new_spanned(path, error.to_string()))?;
+
+    let resolved = resolved
+        .to_str()
+        .ok_or_else(|| syn::Error::new_spanned(path, "the resolved path is not valid UTF-8"))?;
+
+    Ok(quote!({
+        const _: &[u8] = include_bytes!(#resolved);
+        #hash_string
+    }))
+}
+
+fn resolve(path: &str) -> PathBuf {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(manifest_dir) => PathBuf::from(manifest_dir).join(path),
+        None => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::expand_include_hash;
+
+    #[test]
+    fn hashes_the_file_relative_to_the_manifest() {
+        let tokens =
+            expand_include_hash(&parse_quote!("Cargo.toml")).expect("expansion should succeed");
+        let block: syn::ExprBlock = syn::parse2(tokens).expect("output should be a block");
+
+        let Some(syn::Stmt::Expr(
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(literal),
+                ..
+            }),
+            None,
+        )) = block.block.stmts.last()
+        else {
+            panic!("the block should end in a string literal");
+        };
+
+        let contents = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+            .expect("reading the manifest should succeed");
+        let expected = ps_hash_core::hash_encoded(&contents).expect("hash_encoded should succeed");
+
+        assert_eq!(literal.value().as_bytes(), expected);
+    }
+
+    #[test]
+    fn registers_the_file_as_a_dependency() {
+        let tokens =
+            expand_include_hash(&parse_quote!("Cargo.toml")).expect("expansion should succeed");
+
+        assert!(tokens.to_string().contains("include_bytes !"));
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let error = expand_include_hash(&parse_quote!("does/not/exist"))
+            .expect_err("a missing file should be rejected");
+
+        assert!(error.to_string().contains("cannot read"));
+    }
+}
@@ -1,11 +1,21 @@
+mod content_hash;
 mod expand;
+mod include;
 mod input;
+mod literal;
 
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
 fn expand_hash_expr(expr: &syn::Expr) -> proc_macro2::TokenStream {
-    match expand::expand_hash(expr) {
+    expand_with(expr, expand::expand_hash)
+}
+
+fn expand_with(
+    expr: &syn::Expr,
+    expand: fn(&syn::Expr) -> syn::Result<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    match expand(expr) {
         Ok(tokens) => tokens,
         Err(error) => error.to_compile_error(),
     }
@@ -34,6 +44,175 @@ pub fn hash(input: TokenStream) -> TokenStream {
     expand_hash_expr(&expr).into()
 }
 
+/// Derives `ps_hash::ContentHash`, hashing a struct or enum by a canonical
+/// encoding of its fields.
+///
+/// Each field is encoded with `ps_hash::content_hash::encode_field`, in
+/// declaration order, and an enum variant is preceded by its index as a
+/// little-endian `u32`. Field names are not encoded, so renaming a field
+/// keeps the hash, but reordering fields changes it unless their order is
+/// pinned. Type parameters are required to implement `ContentHash`.
+///
+/// The derive understands two attributes:
+///
+/// - `#[content_hash(skip)]` leaves a field out of the encoding.
+/// - `#[content_hash(order = N)]` pins the position of a field, or the index
+///   of a variant. Either every encoded field of a struct or variant has one,
+///   or none does; likewise for the variants of an enum.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash::ContentHash;
+///
+/// #[derive(ContentHash)]
+/// struct Document {
+///     #[content_hash(order = 1)]
+///     body: String,
+///     #[content_hash(order = 0)]
+///     title: String,
+///     #[content_hash(skip)]
+///     cached_len: usize,
+/// }
+///
+/// let document = Document {
+///     body: "Body".into(),
+///     title: "Title".into(),
+///     cached_len: 4,
+/// };
+///
+/// assert_eq!(
+///     document.content_hash().unwrap(),
+///     ("Title", "Body").content_hash().unwrap()
+/// );
+/// ```
+#[proc_macro_derive(ContentHash, attributes(content_hash))]
+pub fn derive_content_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match content_hash::expand_content_hash(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Hashes a file at compile time and expands to its canonical Crockford
+/// Base32 hash as a `&'static str`.
+///
+/// The path is resolved relative to the calling crate's `CARGO_MANIFEST_DIR`,
+/// and the file is registered as a build dependency, so editing it triggers
+/// recompilation. A file that cannot be read is a compile error pointing at
+/// the path.
+///
+/// # Examples
+///
+/// ```
+/// const MANIFEST: &str = ps_hash_macros::include_hash!("Cargo.toml");
+///
+/// assert_eq!(MANIFEST, ps_hash::hash(include_bytes!("../Cargo.toml")).unwrap().to_string());
+/// ```
+#[proc_macro]
+pub fn include_hash(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as syn::LitStr);
+
+    match include::expand_include_hash(&path) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Validates a hash literal at compile time and expands to the
+/// `ps_hash::Hash` it denotes, built in `const` context.
+///
+/// The literal may be in any textual form [`Hash::validate`] accepts, in
+/// Crockford Base32, base64url or hexadecimal. A literal of a binary length,
+/// which [`Hash::validate`] would take as raw bytes, is rejected. An invalid
+/// literal is a compile error, and
+/// so is a literal that only validates after Reed-Solomon correction or
+/// truncation recovery: the error gives the corrected literal, so that the
+/// source gets fixed rather than silently repaired.
+///
+/// The expansion refers to `::ps_hash::Hash`, so the calling crate must
+/// depend on `ps-hash`.
+///
+/// [`Hash::validate`]: ps_hash_core::Hash::validate
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash::Hash;
+///
+/// const HELLO: Hash = ps_hash_macros::hash_literal!(
+///     "RSYNQ1ZC6RGSRY5CNBQJ0D595N5QHSMXA48CHP4T1KV01PFCQ0NGA01VPCWE0P3GSXPDMD7AJ6C6C"
+/// );
+///
+/// assert_eq!(HELLO, ps_hash::hash("hello").unwrap());
+/// ```
+#[proc_macro]
+pub fn hash_literal(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as syn::LitStr);
+
+    match literal::expand_hash_literal(&literal) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Hashes literal input at compile time like [`hash!`], and expands to the
+/// base64url form as a `&'static str`.
+///
+/// # Examples
+///
+/// ```
+/// const HASH: &str = ps_hash_macros::hash_base64!("hello");
+///
+/// assert_eq!(HASH.len(), 64);
+/// assert_eq!(HASH, ps_hash::hash("hello").unwrap().to_base64());
+/// ```
+#[proc_macro]
+pub fn hash_base64(input: TokenStream) -> TokenStream {
+    let expr = parse_macro_input!(input as syn::Expr);
+    expand_with(&expr, expand::expand_hash_base64).into()
+}
+
+/// Hashes literal input at compile time like [`hash!`], and expands to the
+/// internal representation as a `[u8; HASH_SIZE_BIN]` array.
+///
+/// # Examples
+///
+/// ```
+/// const BYTES: [u8; 48] = ps_hash_macros::hash_bytes!("hello");
+///
+/// assert_eq!(&BYTES, ps_hash::hash("hello").unwrap().as_bytes());
+/// ```
+#[proc_macro]
+pub fn hash_bytes(input: TokenStream) -> TokenStream {
+    let expr = parse_macro_input!(input as syn::Expr);
+    expand_with(&expr, expand::expand_hash_bytes).into()
+}
+
+/// Hashes literal input at compile time like [`hash!`], and expands to a
+/// `ps_hash::Hash` built in `const` context, so no validation is left for
+/// run time.
+///
+/// The expansion refers to `::ps_hash::Hash`, so the calling crate must
+/// depend on `ps-hash`.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash::Hash;
+///
+/// const HASH: Hash = ps_hash_macros::hash_value!("hello");
+///
+/// assert_eq!(HASH, ps_hash::hash("hello").unwrap());
+/// ```
+#[proc_macro]
+pub fn hash_value(input: TokenStream) -> TokenStream {
+    let expr = parse_macro_input!(input as syn::Expr);
+    expand_with(&expr, expand::expand_hash_value).into()
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
@@ -0,0 +1,119 @@
+use ps_hash_core::{Hash, Representation, HASH_SIZE_BIN, MIN_RECOVERABLE_BIN};
+use quote::quote;
+use syn::LitStr;
+
+/// Validates a hash literal and expands to the `const` hash it denotes.
+///
+/// A literal the Reed-Solomon codec had to repair is rejected, with the
+/// corrected literal in the error, so that the source gets fixed. So is a
+/// literal of a binary length, whose characters would be taken as raw bytes.
+pub fn expand_hash_literal(literal: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let value = literal.value();
+
+    if (MIN_RECOVERABLE_BIN..=HASH_SIZE_BIN).contains(&value.len()) {
+        return Err(syn::Error::new_spanned(
+            literal,
+            format!(
+                "invalid hash literal: {} bytes is a binary length, write the hash in Crockford Base32, base64url or hex",
+                value.len()
+            ),
+        ));
+    }
+
+    let (hash, report) = Hash::validate_with_report(&value).map_err(|error| {
+        syn::Error::new_spanned(literal, format!("invalid hash literal: {error}"))
+    })?;
+
+    if report.is_truncated() || report.corrected_bytes() > 0 {
+        let corrected = match report.representation() {
+            Representation::Base64 => hash.to_base64(),
+            Representation::Hex => hash.to_hex(),
+            _ => hash.to_crockford(),
+        };
+
+        return Err(syn::Error::new_spanned(
+            literal,
+            format!(
+                "hash literal needed correction ({} corrected bytes{}), use \"{corrected}\" instead",
+                report.corrected_bytes(),
+                if report.is_truncated() { ", truncated" } else { "" },
+            ),
+        ));
+    }
+
+    let inner = hash.as_bytes();
+
+    Ok(quote!(::ps_hash::Hash::from_inner_unchecked([#(#inner),*])))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use ps_hash_core::Hash;
+    use syn::LitStr;
+
+    use super::expand_hash_literal;
+
+    fn literal(value: &str) -> LitStr {
+        LitStr::new(value, proc_macro2::Span::call_site())
+    }
+
+    fn expanded_bytes(value: &str) -> Vec<u8> {
+        let tokens = expand_hash_literal(&literal(value)).expect("expansion should succeed");
+        let call: syn::ExprCall = syn::parse2(tokens).expect("output should be a call");
+        let array = call.args.first().expect("the call should have an argument");
+
+        crate::input::parse_bytes(array).expect("the argument should be a byte array")
+    }
+
+    #[test]
+    fn accepts_canonical_literals() {
+        let hash = Hash::hash(b"literal").expect("hashing should succeed");
+
+        assert_eq!(expanded_bytes(&hash.to_crockford()), hash.as_bytes());
+        assert_eq!(expanded_bytes(&hash.to_base64()), hash.as_bytes());
+        assert_eq!(expanded_bytes(&hash.to_hex()), hash.as_bytes());
+        assert_eq!(
+            expanded_bytes(&hash.to_crockford().to_lowercase()),
+            hash.as_bytes()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_literals() {
+        let error = expand_hash_literal(&literal("not a hash"))
+            .expect_err("an invalid literal should be rejected");
+
+        assert!(error.to_string().contains("invalid hash literal"));
+    }
+
+    #[test]
+    fn rejects_binary_lengths() {
+        for len in [41, 42, 48] {
+            let error = expand_hash_literal(&literal(&"A".repeat(len)))
+                .expect_err("a binary-length literal should be rejected");
+
+            assert!(error.to_string().contains("binary length"), "{len}");
+        }
+    }
+
+    #[test]
+    fn rejects_literals_that_needed_correction() {
+        let hash = Hash::hash(b"typo").expect("hashing should succeed");
+        let mut typo = hash.to_crockford().into_bytes();
+
+        typo[3] = if typo[3] == b'A' { b'B' } else { b'A' };
+
+        let typo = String::from_utf8(typo).expect("the literal should be UTF-8");
+        let error = expand_hash_literal(&literal(&typo))
+            .expect_err("a repaired literal should be rejected");
+
+        assert!(error.to_string().contains(&hash.to_crockford()));
+
+        let truncated = &hash.to_base64()[..60];
+        let error = expand_hash_literal(&literal(truncated))
+            .expect_err("a truncated literal should be rejected");
+
+        assert!(error.to_string().contains(&hash.to_base64()));
+    }
+}
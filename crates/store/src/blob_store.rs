@@ -0,0 +1,47 @@
+use std::io::Read;
+
+use ps_hash::Hash;
+
+use crate::StoreError;
+
+/// A content-addressed store of blobs keyed by their [`Hash`](struct@Hash).
+///
+/// The methods take `&self`, so that one store can be shared between
+/// threads; implementations synchronize internally. The trait is object-safe,
+/// so a backend can be chosen at run time as a `dyn BlobStore`.
+pub trait BlobStore {
+    /// Stores `bytes`, returning their hash.
+    fn put(&self, bytes: &[u8]) -> Result<Hash, StoreError>;
+
+    /// Stores everything `reader` yields, returning its hash.
+    fn put_reader(&self, reader: &mut dyn Read) -> Result<Hash, StoreError>;
+
+    /// Stores everything `reader` yields, which must be the content `hash`
+    /// was computed from.
+    ///
+    /// Because the key is known up front, a store rejects content it could
+    /// not hold before reading any of it, judging by the smallest length the
+    /// key's rounded length field allows. It fails with
+    /// [`StoreError::Mismatch`] as soon as the content exceeds
+    /// [`Hash::data_max_len`].
+    fn put_verified(&self, hash: &Hash, reader: &mut dyn Read) -> Result<(), StoreError>;
+
+    /// Reads the blob keyed by `hash`, or returns `None` if there is none.
+    fn get(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError>;
+
+    /// Opens the blob keyed by `hash` for reading, or returns `None` if there
+    /// is none.
+    ///
+    /// Reading fails with [`ErrorKind::InvalidData`](std::io::ErrorKind) if
+    /// the content turns out not to match its key, at the latest at end of
+    /// input.
+    fn get_reader(&self, hash: &Hash) -> Result<Option<Box<dyn Read + '_>>, StoreError>;
+
+    fn contains(&self, hash: &Hash) -> Result<bool, StoreError>;
+
+    /// Deletes the blob keyed by `hash`, returning whether there was one.
+    fn delete(&self, hash: &Hash) -> Result<bool, StoreError>;
+
+    /// Returns the keys of every stored blob, in ascending order.
+    fn list(&self) -> Result<Vec<Hash>, StoreError>;
+}
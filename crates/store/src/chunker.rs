@@ -0,0 +1,480 @@
+use std::io::{ErrorKind, Read};
+
+use ps_hash::{Hash, HashError};
+
+use crate::{BlobStore, ChunkerError, Manifest, StoreError};
+
+/// The smallest minimum chunk size a [`Chunker`] accepts.
+///
+/// Boundaries depend on a window of the last 64 bytes, so smaller chunks
+/// would not be content-defined.
+pub const MIN_CHUNK_SIZE: usize = 64;
+
+/// Splits data into content-defined chunks with FastCDC.
+///
+/// A boundary is placed wherever a rolling gear hash of the preceding bytes
+/// matches a mask, so inserting or removing bytes only changes the chunks
+/// around the edit, and the rest of the data still deduplicates. Following
+/// FastCDC's normalized chunking, a stricter mask is used before the average
+/// size and a looser one after it, which keeps chunk sizes close to the
+/// average. No boundary is placed before the minimum size, and one is forced
+/// at the maximum size.
+///
+/// The gear table is fixed, so the same data and sizes always produce the
+/// same chunks.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_store::Chunker;
+///
+/// let chunker = Chunker::new(1024, 4096, 16384)?;
+/// let data = vec![7; 100_000];
+/// let mut offset = 0;
+///
+/// for chunk in chunker.chunks(&data) {
+///     let chunk = chunk?;
+///
+///     assert_eq!(chunk.offset, offset);
+///     assert!(chunk.len <= 16384);
+///
+///     offset += chunk.len as u64;
+/// }
+///
+/// assert_eq!(offset, 100_000);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chunker {
+    min: usize,
+    avg: usize,
+    max: usize,
+    mask_small: u64,
+    mask_large: u64,
+}
+
+/// A chunk of data, as produced by a [`Chunker`] or listed in a [`Manifest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Chunk {
+    /// The position of the chunk's first byte in the data.
+    pub offset: u64,
+    pub len: usize,
+    pub hash: Hash,
+}
+
+impl Chunker {
+    /// Creates a chunker producing chunks of `min` to `max` bytes, averaging
+    /// about `avg` bytes; only the last chunk of the data may be shorter than
+    /// `min`.
+    ///
+    /// # Errors
+    ///
+    /// [`ChunkerError::InvalidSizes`] is returned unless
+    /// [`MIN_CHUNK_SIZE`] `<= min <= avg <= max`.
+    pub const fn new(min: usize, avg: usize, max: usize) -> Result<Self, ChunkerError> {
+        if min < MIN_CHUNK_SIZE || min > avg || avg > max {
+            return Err(ChunkerError::InvalidSizes { min, avg, max });
+        }
+
+        let bits = avg.ilog2();
+
+        Ok(Self {
+            min,
+            avg,
+            max,
+            mask_small: mask(bits + 1),
+            mask_large: mask(bits - 1),
+        })
+    }
+
+    pub const fn min_size(&self) -> usize {
+        self.min
+    }
+
+    pub const fn avg_size(&self) -> usize {
+        self.avg
+    }
+
+    pub const fn max_size(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the length of the first chunk of `data`.
+    ///
+    /// This is all of `data` if it is no longer than the minimum size, and
+    /// never more than the maximum size.
+    #[must_use]
+    pub fn cut(&self, data: &[u8]) -> usize {
+        if data.len() <= self.min {
+            return data.len();
+        }
+
+        let end = data.len().min(self.max);
+        let normal = end.min(self.avg);
+        let mut hash = 0u64;
+
+        for (i, &byte) in data.iter().enumerate().take(end).skip(self.min) {
+            hash = (hash << 1).wrapping_add(GEAR[usize::from(byte)]);
+
+            let mask = if i < normal {
+                self.mask_small
+            } else {
+                self.mask_large
+            };
+
+            if hash & mask == 0 {
+                return i + 1;
+            }
+        }
+
+        end
+    }
+
+    /// Splits `data` into chunks, hashing each.
+    pub const fn chunks<'a>(&self, data: &'a [u8]) -> Chunks<'a> {
+        Chunks {
+            chunker: *self,
+            data,
+            offset: 0,
+        }
+    }
+
+    /// Splits everything `reader` yields into chunks, hashing each and
+    /// returning it with its content.
+    ///
+    /// At most [`Chunker::max_size`] bytes plus one read are buffered at a
+    /// time.
+    pub fn chunk_reader<R: Read>(&self, reader: R) -> ReaderChunks<R> {
+        ReaderChunks {
+            chunker: *self,
+            reader,
+            buffer: Vec::new(),
+            offset: 0,
+            eof: false,
+        }
+    }
+
+    /// Stores every chunk of what `reader` yields in `store`, returning the
+    /// manifest needed to reassemble it.
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading, hashing or storing a chunk fails.
+    pub fn store<R: Read, S: BlobStore + ?Sized>(
+        &self,
+        reader: R,
+        store: &S,
+    ) -> Result<Manifest, StoreError> {
+        let mut manifest = Manifest::new();
+
+        for chunk in self.chunk_reader(reader) {
+            let (chunk, data) = chunk?;
+
+            store.put(&data)?;
+            manifest.push(chunk.len, chunk.hash);
+        }
+
+        Ok(manifest)
+    }
+}
+
+impl Default for Chunker {
+    /// Chunks of 16 KiB to 256 KiB, averaging 64 KiB.
+    fn default() -> Self {
+        Self {
+            min: 16 << 10,
+            avg: 64 << 10,
+            max: 256 << 10,
+            mask_small: mask(17),
+            mask_large: mask(15),
+        }
+    }
+}
+
+/// The iterator returned by [`Chunker::chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    chunker: Chunker,
+    data: &'a [u8],
+    offset: u64,
+}
+
+impl Iterator for Chunks<'_> {
+    type Item = Result<Chunk, HashError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let (data, rest) = self.data.split_at(self.chunker.cut(self.data));
+        let offset = self.offset;
+
+        self.data = rest;
+        self.offset += data.len() as u64;
+
+        Some(Hash::hash(data).map(|hash| Chunk {
+            offset,
+            len: data.len(),
+            hash,
+        }))
+    }
+}
+
+/// The iterator returned by [`Chunker::chunk_reader`].
+#[derive(Debug)]
+pub struct ReaderChunks<R> {
+    chunker: Chunker,
+    reader: R,
+    buffer: Vec<u8>,
+    offset: u64,
+    eof: bool,
+}
+
+impl<R: Read> ReaderChunks<R> {
+    /// Reads until a full maximum-size chunk is buffered or the input ends.
+    fn fill(&mut self) -> std::io::Result<()> {
+        let mut block = [0u8; 8192];
+
+        while !self.eof && self.buffer.len() < self.chunker.max {
+            match self.reader.read(&mut block) {
+                Ok(0) => self.eof = true,
+                Ok(n) => self.buffer.extend_from_slice(&block[..n]),
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for ReaderChunks<R> {
+    type Item = Result<(Chunk, Vec<u8>), StoreError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(error) = self.fill() {
+            // Stop after the first error rather than chunking a partial input.
+            self.eof = true;
+            self.buffer.clear();
+
+            return Some(Err(error.into()));
+        }
+
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let len = self.chunker.cut(&self.buffer);
+        let rest = self.buffer.split_off(len);
+        let data = std::mem::replace(&mut self.buffer, rest);
+        let offset = self.offset;
+
+        self.offset += len as u64;
+
+        Some(
+            Hash::hash(&data)
+                .map(|hash| (Chunk { offset, len, hash }, data))
+                .map_err(StoreError::from),
+        )
+    }
+}
+
+/// Returns a mask of the top `bits` bits, which depend on the most input.
+const fn mask(bits: u32) -> u64 {
+    match bits {
+        0 => 0,
+        bits => u64::MAX << (64 - bits),
+    }
+}
+
+/// One pseudo-random value per byte, generated with SplitMix64 from a fixed
+/// seed.
+///
+/// Chunk boundaries, and hence deduplication across versions, depend on this
+/// table, so it must never change.
+static GEAR: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut state = u64::from_be_bytes(*b"ps-hash!");
+    let mut i = 0;
+
+    while i < table.len() {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = state;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+
+    table
+};
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Chunk, Chunker, GEAR};
+    use crate::{ChunkerError, MemoryStore};
+
+    /// Deterministic pseudo-random bytes.
+    fn noise(len: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed;
+
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    fn chunker() -> Chunker {
+        Chunker::new(256, 1024, 4096).expect("the sizes should be valid")
+    }
+
+    fn chunks(chunker: &Chunker, data: &[u8]) -> Vec<Chunk> {
+        chunker
+            .chunks(data)
+            .collect::<Result<_, _>>()
+            .expect("chunking should succeed")
+    }
+
+    #[test]
+    fn rejects_invalid_sizes() {
+        for (min, avg, max) in [
+            (0, 1024, 4096),
+            (63, 64, 64),
+            (2048, 1024, 4096),
+            (256, 8192, 4096),
+        ] {
+            assert_eq!(
+                Chunker::new(min, avg, max),
+                Err(ChunkerError::InvalidSizes { min, avg, max })
+            );
+        }
+
+        assert!(Chunker::new(64, 64, 64).is_ok());
+    }
+
+    #[test]
+    fn default_matches_new() {
+        assert_eq!(
+            Chunker::default(),
+            Chunker::new(16 << 10, 64 << 10, 256 << 10).expect("the sizes should be valid")
+        );
+    }
+
+    #[test]
+    fn gear_table_is_stable() {
+        assert_eq!(GEAR[0], 0x17FF_7F2A_C6CF_8278);
+        assert_eq!(GEAR[255], 0x46A4_5C6D_BBAC_CF55);
+    }
+
+    #[test]
+    fn chunks_cover_the_data_within_bounds() {
+        let chunker = chunker();
+        let data = noise(200_000, 1);
+        let chunks = chunks(&chunker, &data);
+        let mut offset = 0;
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let start = usize::try_from(chunk.offset).expect("the offset should fit");
+            let content = &data[start..start + chunk.len];
+
+            assert_eq!(chunk.offset, offset);
+            assert!(chunk.len <= 4096);
+            assert!(chunk.len >= 256 || i == chunks.len() - 1);
+            assert!(chunk.hash.verify(content).is_ok());
+
+            offset += chunk.len as u64;
+        }
+
+        assert_eq!(offset, 200_000);
+
+        // Normalized chunking keeps the average near the target.
+        let average = data.len() / chunks.len();
+
+        assert!(
+            (512..=2048).contains(&average),
+            "average chunk size {average}"
+        );
+    }
+
+    #[test]
+    fn empty_data_has_no_chunks() {
+        assert!(chunks(&chunker(), b"").is_empty());
+    }
+
+    #[test]
+    fn boundaries_survive_an_insertion() {
+        let chunker = chunker();
+        let original = noise(100_000, 2);
+        let mut edited = original.clone();
+
+        edited.splice(50_000..50_000, *b"inserted");
+
+        let before: HashSet<_> = chunks(&chunker, &original)
+            .into_iter()
+            .map(|chunk| chunk.hash)
+            .collect();
+        let after = chunks(&chunker, &edited);
+        let shared = after
+            .iter()
+            .filter(|chunk| before.contains(&chunk.hash))
+            .count();
+
+        assert!(
+            shared + 3 >= after.len(),
+            "only {shared} of {} chunks are shared",
+            after.len()
+        );
+    }
+
+    #[test]
+    fn reader_chunks_match_slice_chunks() {
+        let chunker = chunker();
+        let data = noise(50_000, 3);
+        let expected = chunks(&chunker, &data);
+
+        let from_reader: Vec<_> = chunker
+            .chunk_reader(data.as_slice())
+            .map(|chunk| {
+                let (chunk, content) = chunk.expect("chunking should succeed");
+
+                assert_eq!(content.len(), chunk.len);
+
+                chunk
+            })
+            .collect();
+
+        assert_eq!(from_reader, expected);
+    }
+
+    #[test]
+    fn store_deduplicates_repeated_chunks() {
+        let chunker = chunker();
+        let block = noise(20_000, 4);
+        let data = [block.as_slice(), &block, &block].concat();
+        let store = MemoryStore::new();
+
+        let manifest = chunker
+            .store(data.as_slice(), &store)
+            .expect("storing should succeed");
+
+        assert_eq!(manifest.total_len(), 60_000);
+        assert!(store.total_bytes() < 40_000);
+        assert_eq!(
+            manifest
+                .reassemble_to_vec(&store)
+                .expect("reassembly should succeed"),
+            data
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use ps_hash::{Hash, HashError, HashValidationError, VerifyError};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum StoreError {
+    #[error("Stored object {hash} failed verification: {source}")]
+    Corrupt {
+        hash: Hash,
+        #[source]
+        source: VerifyError,
+    },
+    #[error("A blob of {len} bytes exceeds the store's capacity of {capacity} bytes")]
+    ExceedsCapacity { len: usize, capacity: usize },
+    #[error(transparent)]
+    HashError(#[from] HashError),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    ManifestError(#[from] ManifestError),
+    #[error("Content does not match {hash}: {source}")]
+    Mismatch {
+        hash: Hash,
+        #[source]
+        source: VerifyError,
+    },
+    #[error("Chunk {0} is missing")]
+    MissingChunk(Hash),
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChunkerError {
+    #[error("Chunk sizes must satisfy 64 <= min <= avg <= max, got {min}, {avg}, {max}")]
+    InvalidSizes { min: usize, avg: usize, max: usize },
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ManifestError {
+    #[error("Chunk {index} has an invalid hash: {source}")]
+    InvalidHash {
+        index: usize,
+        #[source]
+        source: HashValidationError,
+    },
+    #[error("Not a manifest")]
+    InvalidHeader,
+    #[error("The manifest is {0} bytes, which does not match its chunk count")]
+    InvalidLength(usize),
+    #[error("Chunk {index} is {len} bytes, which its hash does not allow")]
+    LengthMismatch { index: usize, len: u64 },
+}
+
+impl StoreError {
+    /// Converts an error from reading content for `hash` through a
+    /// [`VerifyingReader`](ps_hash::VerifyingReader).
+    pub(crate) fn from_verifying(hash: &Hash, error: std::io::Error) -> Self {
+        match error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<VerifyError>())
+        {
+            Some(source) => Self::Mismatch {
+                hash: *hash,
+                source: source.clone(),
+            },
+            None => error.into(),
+        }
+    }
+}
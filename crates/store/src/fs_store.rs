@@ -0,0 +1,494 @@
+use std::{
+    fs::{self, File},
+    io::{self, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use ps_hash::{Hash, HashingReader, VerifyError, VerifyingReader};
+use tempfile::NamedTempFile;
+
+use crate::{BlobStore, StoreError};
+
+/// The number of Crockford characters naming each level of shard directory.
+const SHARD_WIDTH: usize = 2;
+
+/// The number of levels of shard directory.
+const SHARD_DEPTH: usize = 2;
+
+/// A content-addressed store in a directory tree.
+///
+/// An object whose key has the Crockford form `ABCDEF...` is stored at
+/// `<root>/AB/CD/ABCDEF...`, which spreads objects evenly over 1024
+/// directories per level, since the leading characters encode digest bits.
+///
+/// Writes go to a temporary file in the root, which is renamed into place
+/// once complete, so a reader never observes a partial object. Reads re-hash
+/// the content and fail with [`StoreError::Corrupt`] unless it matches its
+/// key; an object larger than its key's [`Hash::data_max_len`] is rejected
+/// before it is read.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_store::FsStore;
+///
+/// let dir = tempfile::tempdir()?;
+/// let store = FsStore::open(dir.path())?;
+///
+/// let hash = store.put(b"content")?;
+///
+/// assert_eq!(store.get(&hash)?.as_deref(), Some(b"content".as_slice()));
+/// assert!(store.delete(&hash)?);
+/// assert_eq!(store.get(&hash)?, None);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct FsStore {
+    root: PathBuf,
+}
+
+impl FsStore {
+    /// Opens the store rooted at `root`, creating the directory if it does
+    /// not exist.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        let root = root.into();
+
+        fs::create_dir_all(&root)?;
+
+        Ok(Self { root })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the path the object keyed by `hash` is stored at.
+    pub fn path(&self, hash: &Hash) -> PathBuf {
+        let name = hash.to_crockford();
+        let mut path = self.root.clone();
+
+        for level in 0..SHARD_DEPTH {
+            path.push(&name[level * SHARD_WIDTH..(level + 1) * SHARD_WIDTH]);
+        }
+
+        path.push(name);
+        path
+    }
+
+    /// Stores `bytes`, returning their hash.
+    ///
+    /// Content that is already stored is not written again.
+    pub fn put(&self, bytes: impl AsRef<[u8]>) -> Result<Hash, StoreError> {
+        let bytes = bytes.as_ref();
+        let hash = Hash::hash(bytes)?;
+
+        if !self.contains(&hash)? {
+            let mut temp = self.temp_file()?;
+
+            temp.write_all(bytes)?;
+            self.commit(temp, &hash)?;
+        }
+
+        Ok(hash)
+    }
+
+    /// Stores everything `reader` yields, returning its hash.
+    ///
+    /// The content is hashed while it streams to disk, so it is never held
+    /// in memory as a whole.
+    pub fn put_reader(&self, reader: impl Read) -> Result<Hash, StoreError> {
+        let mut temp = self.temp_file()?;
+        let mut reader = HashingReader::new(reader);
+
+        io::copy(&mut reader, &mut temp)?;
+
+        let hash = reader.finalize()?;
+
+        if !self.contains(&hash)? {
+            self.commit(temp, &hash)?;
+        }
+
+        Ok(hash)
+    }
+
+    /// Stores everything `reader` yields, which must be the content `hash`
+    /// was computed from.
+    ///
+    /// Nothing is stored unless the content matches; reading stops as soon as
+    /// it exceeds [`Hash::data_max_len`].
+    pub fn put_verified(&self, hash: &Hash, reader: impl Read) -> Result<(), StoreError> {
+        let mut temp = self.temp_file()?;
+        let mut reader = VerifyingReader::new(reader, *hash);
+
+        io::copy(&mut reader, &mut temp)
+            .map_err(|error| StoreError::from_verifying(hash, error))?;
+
+        if !self.contains(hash)? {
+            self.commit(temp, hash)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the object keyed by `hash`, or returns `None` if there is none.
+    pub fn get(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError> {
+        let mut file = match File::open(self.path(hash)) {
+            Ok(file) => file,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        let len = usize::try_from(file.metadata()?.len()).unwrap_or(usize::MAX);
+
+        if len > hash.data_max_len().to_usize() {
+            return Err(StoreError::Corrupt {
+                hash: *hash,
+                source: VerifyError::LengthMismatch {
+                    expected: hash.data_max_len(),
+                    actual: len,
+                },
+            });
+        }
+
+        let mut data = Vec::with_capacity(len);
+
+        file.read_to_end(&mut data)?;
+
+        hash.verify(&data).map_err(|source| StoreError::Corrupt {
+            hash: *hash,
+            source,
+        })?;
+
+        Ok(Some(data))
+    }
+
+    /// Opens the object keyed by `hash` for reading, or returns `None` if
+    /// there is none.
+    ///
+    /// The content is verified as it streams, see [`VerifyingReader`].
+    pub fn get_reader(&self, hash: &Hash) -> Result<Option<VerifyingReader<File>>, StoreError> {
+        match File::open(self.path(hash)) {
+            Ok(file) => Ok(Some(VerifyingReader::new(file, *hash))),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn contains(&self, hash: &Hash) -> Result<bool, StoreError> {
+        Ok(self.path(hash).try_exists()?)
+    }
+
+    /// Deletes the object keyed by `hash`, returning whether there was one.
+    pub fn delete(&self, hash: &Hash) -> Result<bool, StoreError> {
+        match fs::remove_file(self.path(hash)) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Returns the keys of every stored object, in ascending order.
+    ///
+    /// Files that are not named like an object in its shard, such as
+    /// temporary files of writes in progress, are skipped.
+    pub fn list(&self) -> Result<Vec<Hash>, StoreError> {
+        let mut hashes = Vec::new();
+        let mut directories = vec![(self.root.clone(), 0)];
+
+        while let Some((directory, depth)) = directories.pop() {
+            for entry in fs::read_dir(&directory)? {
+                let entry = entry?;
+                let file_type = entry.file_type()?;
+
+                if depth < SHARD_DEPTH {
+                    if file_type.is_dir() {
+                        directories.push((entry.path(), depth + 1));
+                    }
+
+                    continue;
+                }
+
+                let Ok(hash) = Hash::parse_canonical(entry.file_name().as_encoded_bytes()) else {
+                    continue;
+                };
+
+                if file_type.is_file() && entry.path() == self.path(&hash) {
+                    hashes.push(hash);
+                }
+            }
+        }
+
+        hashes.sort_unstable();
+
+        Ok(hashes)
+    }
+
+    /// Creates a temporary file on the same filesystem as the objects, so
+    /// that committing it is a rename.
+    fn temp_file(&self) -> io::Result<NamedTempFile> {
+        tempfile::Builder::new()
+            .prefix(".tmp")
+            .tempfile_in(&self.root)
+    }
+
+    /// Moves a completely written temporary file into place as the object
+    /// keyed by `hash`.
+    fn commit(&self, temp: NamedTempFile, hash: &Hash) -> Result<(), StoreError> {
+        let path = self.path(hash);
+
+        if let Some(shard) = path.parent() {
+            fs::create_dir_all(shard)?;
+        }
+
+        temp.as_file().sync_all()?;
+        temp.persist(&path).map_err(|error| error.error)?;
+
+        Ok(())
+    }
+}
+
+impl BlobStore for FsStore {
+    fn put(&self, bytes: &[u8]) -> Result<Hash, StoreError> {
+        Self::put(self, bytes)
+    }
+
+    fn put_reader(&self, reader: &mut dyn Read) -> Result<Hash, StoreError> {
+        Self::put_reader(self, reader)
+    }
+
+    fn put_verified(&self, hash: &Hash, reader: &mut dyn Read) -> Result<(), StoreError> {
+        Self::put_verified(self, hash, reader)
+    }
+
+    fn get(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError> {
+        Self::get(self, hash)
+    }
+
+    fn get_reader(&self, hash: &Hash) -> Result<Option<Box<dyn Read + '_>>, StoreError> {
+        Ok(Self::get_reader(self, hash)?.map(|reader| Box::new(reader) as Box<dyn Read>))
+    }
+
+    fn contains(&self, hash: &Hash) -> Result<bool, StoreError> {
+        Self::contains(self, hash)
+    }
+
+    fn delete(&self, hash: &Hash) -> Result<bool, StoreError> {
+        Self::delete(self, hash)
+    }
+
+    fn list(&self) -> Result<Vec<Hash>, StoreError> {
+        Self::list(self)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::{fs, io::Read};
+
+    use ps_hash::{Hash, VerifyError};
+    use tempfile::TempDir;
+
+    use super::FsStore;
+    use crate::StoreError;
+
+    fn store() -> (TempDir, FsStore) {
+        let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+        let store = FsStore::open(dir.path()).expect("opening the store should succeed");
+
+        (dir, store)
+    }
+
+    #[test]
+    fn put_then_get_round_trips() {
+        let (_dir, store) = store();
+
+        let hash = store.put(b"round trip").expect("put should succeed");
+
+        assert_eq!(
+            hash,
+            Hash::hash(b"round trip").expect("hashing should succeed")
+        );
+        assert!(store.contains(&hash).expect("contains should succeed"));
+        assert_eq!(
+            store.get(&hash).expect("get should succeed"),
+            Some(b"round trip".to_vec())
+        );
+    }
+
+    #[test]
+    fn put_reader_matches_put() {
+        let (_dir, store) = store();
+        let data = b"streamed".repeat(100_000);
+
+        let streamed = store
+            .put_reader(data.as_slice())
+            .expect("put_reader should succeed");
+
+        assert_eq!(streamed, store.put(&data).expect("put should succeed"));
+        assert_eq!(
+            store.get(&streamed).expect("get should succeed"),
+            Some(data)
+        );
+    }
+
+    #[test]
+    fn objects_are_sharded_by_crockford_prefix() {
+        let (dir, store) = store();
+
+        let hash = store.put(b"sharded").expect("put should succeed");
+        let name = hash.to_crockford();
+
+        assert_eq!(
+            store.path(&hash),
+            dir.path().join(&name[..2]).join(&name[2..4]).join(&name)
+        );
+        assert!(store.path(&hash).is_file());
+    }
+
+    #[test]
+    fn leaves_no_temporary_files_behind() {
+        let (dir, store) = store();
+
+        store.put(b"first").expect("put should succeed");
+        store.put(b"first").expect("put should succeed");
+        store
+            .put_reader(b"second".as_slice())
+            .expect("put_reader should succeed");
+        store
+            .put_reader(b"second".as_slice())
+            .expect("put_reader should succeed");
+
+        for entry in fs::read_dir(dir.path()).expect("listing the root should succeed") {
+            let entry = entry.expect("reading the entry should succeed");
+
+            assert!(
+                entry.path().is_dir(),
+                "{} is not a shard",
+                entry.path().display()
+            );
+        }
+    }
+
+    #[test]
+    fn missing_objects_are_absent() {
+        let (_dir, store) = store();
+        let hash = Hash::hash(b"missing").expect("hashing should succeed");
+
+        assert!(!store.contains(&hash).expect("contains should succeed"));
+        assert_eq!(store.get(&hash).expect("get should succeed"), None);
+        assert!(!store.delete(&hash).expect("delete should succeed"));
+    }
+
+    #[test]
+    fn delete_removes_the_object() {
+        let (_dir, store) = store();
+        let hash = store.put(b"deleted").expect("put should succeed");
+
+        assert!(store.delete(&hash).expect("delete should succeed"));
+        assert!(!store.contains(&hash).expect("contains should succeed"));
+    }
+
+    #[test]
+    fn put_verified_stores_matching_content() {
+        let (_dir, store) = store();
+        let hash = Hash::hash(b"expected").expect("hashing should succeed");
+
+        store
+            .put_verified(&hash, b"expected".as_slice())
+            .expect("matching content should be stored");
+
+        assert_eq!(
+            store.get(&hash).expect("get should succeed"),
+            Some(b"expected".to_vec())
+        );
+    }
+
+    #[test]
+    fn put_verified_rejects_other_content() {
+        let (_dir, store) = store();
+        let hash = Hash::hash(b"expected").expect("hashing should succeed");
+
+        assert!(matches!(
+            store.put_verified(&hash, b"imposter".as_slice()),
+            Err(StoreError::Mismatch {
+                source: VerifyError::DigestMismatch,
+                ..
+            })
+        ));
+        assert!(!store.contains(&hash).expect("contains should succeed"));
+        assert!(store.list().expect("list should succeed").is_empty());
+    }
+
+    #[test]
+    fn get_reader_verifies_while_streaming() {
+        let (_dir, store) = store();
+        let hash = store.put(b"streamed").expect("put should succeed");
+        let mut contents = Vec::new();
+
+        store
+            .get_reader(&hash)
+            .expect("get_reader should succeed")
+            .expect("the object should exist")
+            .read_to_end(&mut contents)
+            .expect("intact content should verify");
+
+        assert_eq!(contents, b"streamed");
+
+        fs::write(store.path(&hash), b"tampered").expect("tampering should succeed");
+
+        assert!(store
+            .get_reader(&hash)
+            .expect("get_reader should succeed")
+            .expect("the object should exist")
+            .read_to_end(&mut Vec::new())
+            .is_err());
+    }
+
+    #[test]
+    fn list_returns_every_key_in_order() {
+        let (dir, store) = store();
+        let mut hashes: Vec<_> = ["one", "two", "three"]
+            .into_iter()
+            .map(|data| store.put(data).expect("put should succeed"))
+            .collect();
+
+        fs::write(dir.path().join("stray"), b"not an object").expect("writing should succeed");
+
+        hashes.sort_unstable();
+
+        assert_eq!(store.list().expect("list should succeed"), hashes);
+    }
+
+    #[test]
+    fn get_rejects_tampered_content() {
+        let (_dir, store) = store();
+        let hash = store.put(b"original").expect("put should succeed");
+
+        fs::write(store.path(&hash), b"tampered").expect("tampering should succeed");
+
+        assert!(matches!(
+            store.get(&hash),
+            Err(StoreError::Corrupt {
+                source: VerifyError::DigestMismatch,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn get_rejects_oversize_objects() {
+        let (_dir, store) = store();
+        let hash = store.put(b"small").expect("put should succeed");
+
+        fs::write(store.path(&hash), vec![0; 1 << 20]).expect("tampering should succeed");
+
+        assert!(matches!(
+            store.get(&hash),
+            Err(StoreError::Corrupt {
+                source: VerifyError::LengthMismatch { actual, .. },
+                ..
+            }) if actual == 1 << 20
+        ));
+    }
+}
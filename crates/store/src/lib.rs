@@ -0,0 +1,24 @@
+//! Content-addressed blob storage keyed by [`Hash`](ps_hash::Hash).
+//!
+//! Every object is stored under the hash of its content, so storing the same
+//! content twice stores it once, and every read is checked against its key.
+//! [`BlobStore`] is the common interface of the backends, [`FsStore`] and
+//! [`MemoryStore`].
+//!
+//! Large data can instead be split by a [`Chunker`] into content-defined
+//! chunks, stored individually so that similar data shares most of them, and
+//! listed in a [`Manifest`] to reassemble it from.
+
+mod blob_store;
+mod chunker;
+mod error;
+mod fs_store;
+mod manifest;
+mod memory_store;
+
+pub use blob_store::BlobStore;
+pub use chunker::{Chunk, Chunker, Chunks, ReaderChunks, MIN_CHUNK_SIZE};
+pub use error::{ChunkerError, ManifestError, StoreError};
+pub use fs_store::FsStore;
+pub use manifest::{ChunkSource, Manifest};
+pub use memory_store::MemoryStore;
@@ -0,0 +1,488 @@
+use std::{collections::HashMap, hash::BuildHasher, io::Write};
+
+use ps_hash::{Hash, HashError, HashValidationError, PackedInt, HASH_SIZE_BIN};
+
+use crate::{BlobStore, Chunk, ManifestError, StoreError};
+
+/// The first bytes of every encoded [`Manifest`], including a format version.
+const HEADER: &[u8; 8] = b"PSHMNF\x00\x01";
+
+/// The encoded size of one chunk: its length, then its hash.
+const ENTRY_SIZE: usize = 8 + HASH_SIZE_BIN;
+
+/// The list of chunks some data was split into, in order.
+///
+/// A manifest has a stable binary encoding, [`Manifest::to_bytes`], so it can
+/// be stored like any other blob and referred to by a single
+/// [`Hash`](struct@Hash): [`Manifest::put`] stores it, [`Manifest::get`]
+/// loads it back.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_store::{Chunker, Manifest, MemoryStore};
+///
+/// let store = MemoryStore::new();
+/// let data = vec![42; 100_000];
+///
+/// let manifest = Chunker::new(1024, 4096, 16384)?.store(data.as_slice(), &store)?;
+/// let key = manifest.put(&store)?;
+///
+/// let loaded = Manifest::get(&store, &key)?.expect("the manifest was stored");
+///
+/// assert_eq!(loaded.reassemble_to_vec(&store)?, data);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    chunks: Vec<Chunk>,
+    total_len: u64,
+}
+
+/// Somewhere to look chunks up by their hash.
+///
+/// Every [`BlobStore`] is a chunk source, so chunks can be reassembled from a
+/// directory with [`FsStore`](crate::FsStore), and so is an in-memory
+/// [`HashMap`].
+pub trait ChunkSource {
+    /// Returns the chunk keyed by `hash`, or `None` if there is none.
+    ///
+    /// The content is not trusted: [`Manifest::reassemble`] verifies it.
+    fn chunk(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError>;
+}
+
+impl<T: BlobStore + ?Sized> ChunkSource for T {
+    fn chunk(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError> {
+        self.get(hash)
+    }
+}
+
+impl<S: BuildHasher> ChunkSource for HashMap<Hash, Vec<u8>, S> {
+    fn chunk(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError> {
+        Ok(self.get(hash).cloned())
+    }
+}
+
+impl Manifest {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a chunk of `len` bytes keyed by `hash`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length would overflow a `u64`. Only forged
+    /// lengths can get there, and [`Manifest::from_bytes`] rejects those
+    /// instead.
+    pub fn push(&mut self, len: usize, hash: Hash) {
+        if self.try_push(len, hash).is_none() {
+            panic!("the total length of a manifest overflowed");
+        }
+    }
+
+    /// Appends a chunk like [`Manifest::push`], or returns `None` without
+    /// changing the manifest if the total length would overflow.
+    fn try_push(&mut self, len: usize, hash: Hash) -> Option<()> {
+        let total_len = self.total_len.checked_add(u64::try_from(len).ok()?)?;
+
+        self.chunks.push(Chunk {
+            offset: self.total_len,
+            len,
+            hash,
+        });
+        self.total_len = total_len;
+
+        Some(())
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    /// Returns the length of the data, the sum of the chunk lengths.
+    pub const fn total_len(&self) -> u64 {
+        self.total_len
+    }
+
+    /// Encodes the manifest.
+    ///
+    /// The encoding is a fixed header, the chunk count as a little-endian
+    /// `u64`, then each chunk's length as a little-endian `u64` followed by
+    /// its [`HASH_SIZE_BIN`] hash bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER.len() + 8 + self.chunks.len() * ENTRY_SIZE);
+
+        bytes.extend_from_slice(HEADER);
+        bytes.extend_from_slice(&(self.chunks.len() as u64).to_le_bytes());
+
+        for chunk in &self.chunks {
+            bytes.extend_from_slice(&(chunk.len as u64).to_le_bytes());
+            bytes.extend_from_slice(chunk.hash.as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decodes a manifest encoded by [`Manifest::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ManifestError::InvalidHeader`] is returned if `bytes` do not start
+    ///   with the manifest header.
+    /// - [`ManifestError::InvalidLength`] is returned if the length of `bytes`
+    ///   does not match the chunk count.
+    /// - [`ManifestError::InvalidHash`] is returned if a chunk's hash is
+    ///   invalid or needs repair.
+    /// - [`ManifestError::LengthMismatch`] is returned if a chunk's length
+    ///   contradicts the length stored in its hash, or would overflow the
+    ///   total length.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ManifestError> {
+        let body = bytes
+            .strip_prefix(HEADER)
+            .ok_or(ManifestError::InvalidHeader)?;
+
+        let (count, entries) = body
+            .split_first_chunk::<8>()
+            .ok_or(ManifestError::InvalidLength(bytes.len()))?;
+
+        let count = u64::from_le_bytes(*count);
+
+        if !entries.len().is_multiple_of(ENTRY_SIZE) || (entries.len() / ENTRY_SIZE) as u64 != count
+        {
+            return Err(ManifestError::InvalidLength(bytes.len()));
+        }
+
+        let mut manifest = Self::new();
+
+        for (index, entry) in entries.chunks_exact(ENTRY_SIZE).enumerate() {
+            let (len, hash) = entry.split_at(8);
+            let len = u64::from_le_bytes(len.try_into().unwrap_or_default());
+
+            let hash =
+                parse_hash(hash).map_err(|source| ManifestError::InvalidHash { index, source })?;
+
+            usize::try_from(len)
+                .ok()
+                .filter(|&len| PackedInt::from_usize(len) == hash.data_max_len())
+                .and_then(|len| manifest.try_push(len, hash))
+                .ok_or(ManifestError::LengthMismatch { index, len })?;
+        }
+
+        Ok(manifest)
+    }
+
+    /// Returns the hash of the manifest's encoding, which is its key once
+    /// stored.
+    ///
+    /// # Errors
+    ///
+    /// Fails only if hashing fails.
+    pub fn hash(&self) -> Result<Hash, HashError> {
+        Hash::hash(self.to_bytes())
+    }
+
+    /// Stores the manifest's encoding in `store`, returning its key.
+    ///
+    /// # Errors
+    ///
+    /// Fails if storing fails.
+    pub fn put<S: BlobStore + ?Sized>(&self, store: &S) -> Result<Hash, StoreError> {
+        store.put(&self.to_bytes())
+    }
+
+    /// Loads the manifest keyed by `hash` from `store`, or returns `None` if
+    /// there is none.
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading fails, or with [`StoreError::ManifestError`] if the
+    /// blob is not a manifest.
+    pub fn get<S: BlobStore + ?Sized>(store: &S, hash: &Hash) -> Result<Option<Self>, StoreError> {
+        match store.get(hash)? {
+            Some(bytes) => Ok(Some(Self::from_bytes(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Writes the data back together from its chunks in `source`.
+    ///
+    /// Every chunk is re-hashed and checked against the manifest before it
+    /// is written, so `writer` receives only verified content, though it may
+    /// have received a prefix of the data when an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`StoreError::MissingChunk`] is returned if `source` lacks a chunk.
+    /// - [`StoreError::Mismatch`] is returned if a chunk does not match its
+    ///   hash.
+    /// - Errors of `source` and `writer` are passed through.
+    pub fn reassemble<C: ChunkSource + ?Sized, W: Write>(
+        &self,
+        source: &C,
+        writer: &mut W,
+    ) -> Result<(), StoreError> {
+        for chunk in &self.chunks {
+            let data = source
+                .chunk(&chunk.hash)?
+                .ok_or(StoreError::MissingChunk(chunk.hash))?;
+
+            chunk
+                .hash
+                .verify(&data)
+                .map_err(|source| StoreError::Mismatch {
+                    hash: chunk.hash,
+                    source,
+                })?;
+
+            writer.write_all(&data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reassembles the data in memory; see [`Manifest::reassemble`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Manifest::reassemble`].
+    pub fn reassemble_to_vec<C: ChunkSource + ?Sized>(
+        &self,
+        source: &C,
+    ) -> Result<Vec<u8>, StoreError> {
+        // The lengths come from the manifest, which may be forged, so the
+        // buffer grows with the verified chunks instead of being sized
+        // upfront.
+        let mut data = Vec::new();
+
+        self.reassemble(source, &mut data)?;
+
+        Ok(data)
+    }
+}
+
+impl FromIterator<Chunk> for Manifest {
+    /// Collects chunks in order; their offsets are recomputed from the
+    /// lengths.
+    fn from_iter<I: IntoIterator<Item = Chunk>>(iter: I) -> Self {
+        let mut manifest = Self::new();
+
+        for chunk in iter {
+            manifest.push(chunk.len, chunk.hash);
+        }
+
+        manifest
+    }
+}
+
+/// Parses the hash bytes of an entry, which must need no repair.
+fn parse_hash(bytes: &[u8]) -> Result<Hash, HashValidationError> {
+    let hash = Hash::validate(bytes)?;
+
+    if hash.as_bytes() != bytes {
+        return Err(HashValidationError::NonCanonical);
+    }
+
+    Ok(hash)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ps_hash::{Hash, HashValidationError, PackedInt, VerifyError};
+
+    use super::{Manifest, HEADER};
+    use crate::{Chunker, FsStore, ManifestError, MemoryStore, StoreError};
+
+    fn data() -> Vec<u8> {
+        (0..30_000u32).map(|i| (i * 7 % 251) as u8).collect()
+    }
+
+    fn chunked(data: &[u8]) -> (Manifest, HashMap<Hash, Vec<u8>>) {
+        let chunker = Chunker::new(256, 1024, 4096).expect("the sizes should be valid");
+        let mut chunks = HashMap::new();
+
+        let manifest = chunker
+            .chunk_reader(data)
+            .map(|chunk| {
+                let (chunk, content) = chunk.expect("chunking should succeed");
+
+                chunks.insert(chunk.hash, content);
+
+                chunk
+            })
+            .collect();
+
+        (manifest, chunks)
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let (manifest, _) = chunked(&data());
+        let bytes = manifest.to_bytes();
+
+        assert!(bytes.starts_with(HEADER));
+        assert_eq!(
+            Manifest::from_bytes(&bytes).expect("decoding should succeed"),
+            manifest
+        );
+        assert_eq!(
+            manifest.hash().expect("hashing should succeed"),
+            Hash::hash(&bytes).expect("hashing should succeed")
+        );
+    }
+
+    #[test]
+    fn empty_manifest_round_trips() {
+        let manifest = Manifest::new();
+
+        assert_eq!(
+            Manifest::from_bytes(&manifest.to_bytes()).expect("decoding should succeed"),
+            manifest
+        );
+        assert_eq!(
+            manifest
+                .reassemble_to_vec(&MemoryStore::new())
+                .expect("reassembly should succeed"),
+            b""
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_encodings() {
+        let (manifest, _) = chunked(&data());
+        let bytes = manifest.to_bytes();
+
+        assert_eq!(
+            Manifest::from_bytes(b"not a manifest"),
+            Err(ManifestError::InvalidHeader)
+        );
+        assert_eq!(
+            Manifest::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(ManifestError::InvalidLength(bytes.len() - 1))
+        );
+
+        let mut corrupted = bytes.clone();
+        let last = corrupted.len() - 1;
+
+        corrupted[last] ^= 1;
+
+        assert_eq!(
+            Manifest::from_bytes(&corrupted),
+            Err(ManifestError::InvalidHash {
+                index: manifest.chunks().len() - 1,
+                source: HashValidationError::NonCanonical
+            })
+        );
+
+        let mut mislabelled = bytes;
+
+        mislabelled[HEADER.len() + 8] ^= 1;
+
+        assert!(matches!(
+            Manifest::from_bytes(&mislabelled),
+            Err(ManifestError::LengthMismatch { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn reassembles_from_a_map() {
+        let data = data();
+        let (manifest, chunks) = chunked(&data);
+
+        assert_eq!(manifest.total_len(), data.len() as u64);
+        assert_eq!(
+            manifest
+                .reassemble_to_vec(&chunks)
+                .expect("reassembly should succeed"),
+            data
+        );
+    }
+
+    #[test]
+    fn reassembles_from_a_directory() {
+        let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+        let store = FsStore::open(dir.path()).expect("opening the store should succeed");
+        let data = data();
+
+        let manifest = Chunker::new(256, 1024, 4096)
+            .expect("the sizes should be valid")
+            .store(data.as_slice(), &store)
+            .expect("storing should succeed");
+        let key = manifest.put(&store).expect("put should succeed");
+
+        let loaded = Manifest::get(&store, &key)
+            .expect("get should succeed")
+            .expect("the manifest should exist");
+
+        assert_eq!(loaded, manifest);
+        assert_eq!(
+            loaded
+                .reassemble_to_vec(&store)
+                .expect("reassembly should succeed"),
+            data
+        );
+    }
+
+    #[test]
+    fn detects_missing_and_corrupt_chunks() {
+        let (manifest, mut chunks) = chunked(&data());
+        let first = manifest.chunks()[0].hash;
+        let second = manifest.chunks()[1].hash;
+
+        chunks.remove(&first);
+
+        assert!(matches!(
+            manifest.reassemble_to_vec(&chunks),
+            Err(StoreError::MissingChunk(hash)) if hash == first
+        ));
+
+        let (_, mut chunks) = chunked(&data());
+
+        chunks.get_mut(&second).expect("the chunk should exist")[0] ^= 1;
+
+        let mut written = Vec::new();
+
+        assert!(matches!(
+            manifest.reassemble(&chunks, &mut written),
+            Err(StoreError::Mismatch {
+                hash,
+                source: VerifyError::DigestMismatch,
+            }) if hash == second
+        ));
+        assert_eq!(written, chunks[&first], "only verified chunks are written");
+    }
+
+    #[test]
+    fn rejects_forged_lengths_that_overflow() {
+        let forged = Hash::from_parts(&[7; 32], PackedInt::from_usize(usize::MAX))
+            .expect("building the hash should succeed");
+        let mut manifest = Manifest::new();
+
+        manifest.push(usize::MAX, forged);
+
+        // Nothing is allocated for the claimed length.
+        assert!(matches!(
+            manifest.reassemble_to_vec(&HashMap::<Hash, Vec<u8>>::new()),
+            Err(StoreError::MissingChunk(hash)) if hash == forged
+        ));
+
+        let mut bytes = manifest.to_bytes();
+        let entry = bytes[HEADER.len() + 8..].to_vec();
+
+        bytes[HEADER.len()..HEADER.len() + 8].copy_from_slice(&2u64.to_le_bytes());
+        bytes.extend_from_slice(&entry);
+
+        assert_eq!(
+            Manifest::from_bytes(&bytes),
+            Err(ManifestError::LengthMismatch {
+                index: 1,
+                len: usize::MAX as u64
+            })
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{self, Cursor, Read},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use ps_hash::{Hash, PackedInt, VerifyingReader};
+
+use crate::{BlobStore, StoreError};
+
+/// The most [`BlobStore::put_verified`] reserves upfront for a blob whose
+/// length it only knows from the key.
+const MAX_PREALLOCATION: usize = 64 * 1024;
+
+/// An in-memory content-addressed store.
+///
+/// A store created with [`MemoryStore::with_capacity`] holds at most that
+/// many bytes of blob content, evicting the least recently used blobs to make
+/// room for new ones; a blob larger than the whole capacity is rejected.
+/// Reading a blob with [`BlobStore::get`] or [`BlobStore::get_reader`] counts
+/// as using it, [`BlobStore::contains`] does not.
+///
+/// # Examples
+///
+/// ```
+/// use ps_hash_store::{BlobStore, MemoryStore};
+///
+/// let store = MemoryStore::with_capacity(10);
+///
+/// let old = store.put(b"old")?;
+/// let new = store.put(b"new")?;
+///
+/// store.get(&old)?;
+/// store.put(b"newest")?;
+///
+/// // Evicting `new` made room for six more bytes.
+/// assert!(store.contains(&old)?);
+/// assert!(!store.contains(&new)?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    capacity: Option<usize>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    blobs: HashMap<Hash, Blob>,
+    /// Every blob's key, by the tick it was last used at.
+    recency: BTreeMap<u64, Hash>,
+    clock: u64,
+    total: usize,
+}
+
+#[derive(Debug)]
+struct Blob {
+    data: Arc<[u8]>,
+    used: u64,
+}
+
+impl MemoryStore {
+    /// Creates a store without a capacity, which never evicts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a store holding at most `capacity` bytes of blob content.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            state: Mutex::default(),
+        }
+    }
+
+    pub const fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Returns the total size of the stored blobs, in bytes.
+    pub fn total_bytes(&self) -> usize {
+        self.state().total
+    }
+
+    pub fn len(&self) -> usize {
+        self.state().blobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.state().blobs.is_empty()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // Every critical section leaves the state consistent, so a panic in
+        // another thread cannot have corrupted it.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn check_capacity(&self, len: usize) -> Result<(), StoreError> {
+        match self.capacity {
+            Some(capacity) if len > capacity => Err(StoreError::ExceedsCapacity { len, capacity }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads `reader` to the end into `data`, stopping one byte past the
+    /// capacity, so that an oversized blob is detected without being read
+    /// in full.
+    fn read_capped(&self, reader: &mut dyn Read, data: &mut Vec<u8>) -> io::Result<usize> {
+        match self.capacity {
+            Some(capacity) => {
+                let limit = u64::try_from(capacity)
+                    .unwrap_or(u64::MAX)
+                    .saturating_add(1);
+
+                reader.take(limit).read_to_end(data)
+            }
+            None => reader.read_to_end(data),
+        }
+    }
+
+    fn insert(&self, hash: Hash, data: Arc<[u8]>) {
+        let mut state = self.state();
+
+        if state.touch(&hash).is_some() {
+            return;
+        }
+
+        state.total += data.len();
+
+        let used = state.tick();
+
+        state.recency.insert(used, hash);
+        state.blobs.insert(hash, Blob { data, used });
+
+        if let Some(capacity) = self.capacity {
+            // The new blob fits on its own, so it is never the one evicted.
+            while state.total > capacity {
+                let Some((_, oldest)) = state.recency.pop_first() else {
+                    break;
+                };
+
+                if let Some(blob) = state.blobs.remove(&oldest) {
+                    state.total -= blob.data.len();
+                }
+            }
+        }
+    }
+}
+
+impl State {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// Marks the blob keyed by `hash` as just used, and returns its content.
+    fn touch(&mut self, hash: &Hash) -> Option<Arc<[u8]>> {
+        let used = self.tick();
+        let blob = self.blobs.get_mut(hash)?;
+
+        self.recency.remove(&blob.used);
+        self.recency.insert(used, *hash);
+        blob.used = used;
+
+        Some(blob.data.clone())
+    }
+}
+
+impl BlobStore for MemoryStore {
+    fn put(&self, bytes: &[u8]) -> Result<Hash, StoreError> {
+        self.check_capacity(bytes.len())?;
+
+        let hash = Hash::hash(bytes)?;
+
+        self.insert(hash, bytes.into());
+
+        Ok(hash)
+    }
+
+    fn put_reader(&self, reader: &mut dyn Read) -> Result<Hash, StoreError> {
+        let mut data = Vec::new();
+
+        self.read_capped(reader, &mut data)?;
+        self.check_capacity(data.len())?;
+
+        let hash = Hash::hash(&data)?;
+
+        self.insert(hash, data.into());
+
+        Ok(hash)
+    }
+
+    fn put_verified(&self, hash: &Hash, reader: &mut dyn Read) -> Result<(), StoreError> {
+        self.check_capacity(min_data_len(hash))?;
+
+        // The length field is only an upper bound claimed by the key, so at
+        // most a small buffer is reserved for it upfront.
+        let mut data = Vec::with_capacity(hash.data_max_len().to_usize().min(MAX_PREALLOCATION));
+
+        self.read_capped(&mut VerifyingReader::new(reader, *hash), &mut data)
+            .map_err(|error| StoreError::from_verifying(hash, error))?;
+
+        // The key only bounds the length from below, so a blob cut off at the
+        // capacity is rejected here, before it counts as verified.
+        self.check_capacity(data.len())?;
+        self.insert(*hash, data.into());
+
+        Ok(())
+    }
+
+    fn get(&self, hash: &Hash) -> Result<Option<Vec<u8>>, StoreError> {
+        Ok(self.state().touch(hash).map(|data| data.to_vec()))
+    }
+
+    fn get_reader(&self, hash: &Hash) -> Result<Option<Box<dyn Read + '_>>, StoreError> {
+        Ok(self
+            .state()
+            .touch(hash)
+            .map(|data| Box::new(Cursor::new(data)) as Box<dyn Read>))
+    }
+
+    fn contains(&self, hash: &Hash) -> Result<bool, StoreError> {
+        Ok(self.state().blobs.contains_key(hash))
+    }
+
+    fn delete(&self, hash: &Hash) -> Result<bool, StoreError> {
+        let mut state = self.state();
+
+        let Some(blob) = state.blobs.remove(hash) else {
+            return Ok(false);
+        };
+
+        state.recency.remove(&blob.used);
+        state.total -= blob.data.len();
+
+        Ok(true)
+    }
+
+    fn list(&self) -> Result<Vec<Hash>, StoreError> {
+        let mut hashes: Vec<Hash> = self.state().blobs.keys().copied().collect();
+
+        hashes.sort_unstable();
+
+        Ok(hashes)
+    }
+}
+
+/// Returns the smallest content length that `hash`'s rounded length field
+/// allows.
+fn min_data_len(hash: &Hash) -> usize {
+    match hash.data_max_len().to_inner_u16().checked_sub(1) {
+        Some(below) => PackedInt::from_inner_u16(below)
+            .to_usize()
+            .saturating_add(1),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use std::io::Read;
+
+    use ps_hash::{Hash, PackedInt, VerifyError};
+
+    use super::MemoryStore;
+    use crate::{BlobStore, StoreError};
+
+    fn hash(data: &[u8]) -> Hash {
+        Hash::hash(data).expect("hashing should succeed")
+    }
+
+    #[test]
+    fn put_then_get_round_trips() {
+        let store = MemoryStore::new();
+
+        let key = store.put(b"memory").expect("put should succeed");
+
+        assert_eq!(key, hash(b"memory"));
+        assert_eq!(
+            store.get(&key).expect("get should succeed"),
+            Some(b"memory".to_vec())
+        );
+    }
+
+    #[test]
+    fn duplicate_content_is_stored_once() {
+        let store = MemoryStore::new();
+
+        store.put(b"twice").expect("put should succeed");
+        store
+            .put_reader(&mut b"twice".as_slice())
+            .expect("put_reader should succeed");
+
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.total_bytes(), 5);
+    }
+
+    #[test]
+    fn get_reader_streams_the_content() {
+        let store = MemoryStore::new();
+        let key = store.put(b"streamed").expect("put should succeed");
+        let mut contents = Vec::new();
+
+        store
+            .get_reader(&key)
+            .expect("get_reader should succeed")
+            .expect("the blob should exist")
+            .read_to_end(&mut contents)
+            .expect("reading should succeed");
+
+        assert_eq!(contents, b"streamed");
+        assert!(store
+            .get_reader(&hash(b"missing"))
+            .expect("get_reader should succeed")
+            .is_none());
+    }
+
+    #[test]
+    fn delete_and_list() {
+        let store = MemoryStore::new();
+        let one = store.put(b"one").expect("put should succeed");
+        let two = store.put(b"two").expect("put should succeed");
+
+        let mut expected = vec![one, two];
+        expected.sort_unstable();
+
+        assert_eq!(store.list().expect("list should succeed"), expected);
+        assert!(store.delete(&one).expect("delete should succeed"));
+        assert!(!store.delete(&one).expect("delete should succeed"));
+        assert_eq!(store.list().expect("list should succeed"), vec![two]);
+        assert_eq!(store.total_bytes(), 3);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_blobs() {
+        let store = MemoryStore::with_capacity(12);
+
+        let a = store.put(b"aaaa").expect("put should succeed");
+        let b = store.put(b"bbbb").expect("put should succeed");
+        let c = store.put(b"cccc").expect("put should succeed");
+
+        store.get(&a).expect("get should succeed");
+
+        let d = store.put(b"dddd").expect("put should succeed");
+
+        assert!(store.contains(&a).expect("contains should succeed"));
+        assert!(!store.contains(&b).expect("contains should succeed"));
+        assert!(store.contains(&c).expect("contains should succeed"));
+        assert!(store.contains(&d).expect("contains should succeed"));
+        assert_eq!(store.total_bytes(), 12);
+    }
+
+    #[test]
+    fn rejects_blobs_larger_than_the_capacity() {
+        let store = MemoryStore::with_capacity(4);
+
+        assert!(matches!(
+            store.put(b"too large"),
+            Err(StoreError::ExceedsCapacity {
+                len: 9,
+                capacity: 4
+            })
+        ));
+        assert!(matches!(
+            store.put_reader(&mut b"too large".as_slice()),
+            Err(StoreError::ExceedsCapacity { capacity: 4, .. })
+        ));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn put_verified_rejects_oversize_keys_up_front() {
+        let store = MemoryStore::with_capacity(4);
+        let key = hash(&[0; 100]);
+        let mut reader = [0u8; 100].as_slice();
+
+        assert!(matches!(
+            store.put_verified(&key, &mut reader),
+            Err(StoreError::ExceedsCapacity {
+                len: 100,
+                capacity: 4
+            })
+        ));
+        assert_eq!(reader.len(), 100, "nothing should have been read");
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn min_data_len_is_the_bottom_of_the_length_bucket() {
+        for len in [0, 1, 255, 256, 1000, 1001, 1002, 1003, 65_537] {
+            let key = hash(&vec![3; len]);
+            let min = super::min_data_len(&key);
+
+            assert!(min <= len, "{len}");
+            assert_eq!(PackedInt::from_usize(min), key.data_max_len(), "{len}");
+
+            if min > 0 {
+                assert_ne!(PackedInt::from_usize(min - 1), key.data_max_len(), "{len}");
+            }
+        }
+    }
+
+    #[test]
+    fn put_verified_accepts_blobs_that_fill_the_capacity() {
+        let data = [7u8; 1001];
+        let key = hash(&data);
+        let store = MemoryStore::with_capacity(data.len());
+
+        assert!(key.data_max_len().to_usize() > data.len());
+
+        store
+            .put_verified(&key, &mut data.as_slice())
+            .expect("a blob that fits exactly should be stored");
+
+        assert_eq!(store.total_bytes(), data.len());
+    }
+
+    #[test]
+    fn put_verified_checks_the_content() {
+        let store = MemoryStore::new();
+        let key = hash(b"expected");
+
+        assert!(matches!(
+            store.put_verified(&key, &mut b"imposter".as_slice()),
+            Err(StoreError::Mismatch {
+                source: VerifyError::DigestMismatch,
+                ..
+            })
+        ));
+        assert!(store.is_empty());
+
+        store
+            .put_verified(&key, &mut b"expected".as_slice())
+            .expect("matching content should be stored");
+
+        assert_eq!(
+            store.get(&key).expect("get should succeed"),
+            Some(b"expected".to_vec())
+        );
+    }
+
+    #[test]
+    fn stores_are_interchangeable_behind_the_trait() {
+        let dir = tempfile::tempdir().expect("creating a temporary directory should succeed");
+        let stores: [Box<dyn BlobStore>; 2] = [
+            Box::new(MemoryStore::new()),
+            Box::new(crate::FsStore::open(dir.path()).expect("opening the store should succeed")),
+        ];
+
+        for store in &stores {
+            let key = store.put(b"shared").expect("put should succeed");
+
+            assert_eq!(store.list().expect("list should succeed"), vec![key]);
+            assert_eq!(
+                store.get(&key).expect("get should succeed"),
+                Some(b"shared".to_vec())
+            );
+        }
+    }
+}
@@ -1 +1,4 @@
-pub use ps_hash_core::{HashError, HashValidationError};
+pub use ps_hash_core::{
+    HashError, HashReadError, HashValidationError, IdentifierError, MerkleError, MultiformatError,
+    PrefixError, VerifyError,
+};
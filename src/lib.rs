@@ -1,12 +1,25 @@
 #![allow(clippy::missing_errors_doc)]
+
+// Lets the expansion of `hash_value!` name `::ps_hash` inside this crate too.
+extern crate self as ps_hash;
+
 pub mod error;
 pub use error::*;
+#[cfg(feature = "serde")]
+pub use ps_hash_core::serde;
+#[cfg(feature = "rayon")]
+pub use ps_hash_core::ParallelHashIterator;
 pub use ps_hash_core::{
-    encoding, hash, Hash, PackedInt, DIGEST_SIZE, HASH_SIZE_BASE64, HASH_SIZE_BIN,
-    HASH_SIZE_COMPACT, HASH_SIZE_CROCKFORD, MIN_RECOVERABLE_BASE64, MIN_RECOVERABLE_BIN,
-    MIN_RECOVERABLE_CROCKFORD, PARITY, PARITY_OFFSET, PARITY_SIZE, RS, SIZE_SIZE,
+    content_hash, encoding, hash, hash_many, merkle, multiformats, ContentHash, Correction, Hash,
+    HashBuildHasher, HashHasher, HashMap, HashSet, Hasher, HashingReader, PackedInt, PrefixIndex,
+    Representation, ValidationReport, VerifyingReader, DIGEST_SIZE, HASH_SIZE_BASE64,
+    HASH_SIZE_BIN, HASH_SIZE_COMPACT, HASH_SIZE_CROCKFORD, HASH_SIZE_HEX, MIN_RECOVERABLE_BASE64,
+    MIN_RECOVERABLE_BIN, MIN_RECOVERABLE_CROCKFORD, MIN_RECOVERABLE_HEX, PARITY, PARITY_OFFSET,
+    PARITY_SIZE, RS, SIZE_SIZE,
+};
+pub use ps_hash_macros::{
+    hash, hash_base64, hash_bytes, hash_literal, hash_value, include_hash, ContentHash,
 };
-pub use ps_hash_macros::hash;
 
 #[cfg(test)]
 pub mod tests;
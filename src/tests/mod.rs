@@ -184,3 +184,183 @@ pub fn hash_macro_escape_sequences() -> Result<(), HashError> {
 
     Ok(())
 }
+
+#[test]
+pub fn hash_value_macro() -> Result<(), HashError> {
+    const MACRO_HASH: Hash = crate::hash_value!("hello");
+    let runtime_hash = crate::hash("hello")?;
+
+    assert_eq!(MACRO_HASH, runtime_hash);
+    assert_eq!(MACRO_HASH.to_string(), crate::hash!("hello"));
+
+    Ok(())
+}
+
+#[test]
+pub fn hash_base64_macro() -> Result<(), HashError> {
+    const MACRO_HASH: &str = crate::hash_base64!(b"hello");
+    let runtime_hash = crate::hash("hello")?.to_base64();
+
+    assert_eq!(MACRO_HASH, runtime_hash);
+
+    Ok(())
+}
+
+#[test]
+pub fn hash_bytes_macro() -> Result<(), HashError> {
+    const MACRO_HASH: [u8; crate::HASH_SIZE_BIN] = crate::hash_bytes!([0u8; 1000]);
+    let runtime_hash = crate::hash([0u8; 1000])?;
+
+    assert_eq!(&MACRO_HASH, runtime_hash.as_bytes());
+
+    Ok(())
+}
+
+#[test]
+pub fn include_hash_macro() -> Result<(), HashError> {
+    const MACRO_HASH: &str = crate::include_hash!("Cargo.toml");
+    let runtime_hash = crate::hash(include_bytes!("../../Cargo.toml"))?.to_string();
+
+    assert_eq!(MACRO_HASH, runtime_hash);
+
+    Ok(())
+}
+
+#[test]
+pub fn hash_literal_macro() -> Result<(), HashError> {
+    const MACRO_HASH: Hash = crate::hash_literal!(
+        "RSYNQ1ZC6RGSRY5CNBQJ0D595N5QHSMXA48CHP4T1KV01PFCQ0NGA01VPCWE0P3GSXPDMD7AJ6C6C"
+    );
+
+    assert_eq!(MACRO_HASH, crate::hash_value!("hello"));
+
+    Ok(())
+}
+
+mod content_hash {
+    use std::collections::BTreeMap;
+
+    use crate::{ContentHash, HashError};
+
+    #[derive(ContentHash)]
+    struct Record {
+        name: String,
+        size: u64,
+        tags: Vec<String>,
+    }
+
+    #[derive(ContentHash)]
+    #[allow(dead_code)]
+    struct Reordered {
+        #[content_hash(order = 2)]
+        tags: Vec<String>,
+        #[content_hash(order = 0)]
+        name: String,
+        #[content_hash(skip)]
+        cache: Option<usize>,
+        #[content_hash(order = 1)]
+        size: u64,
+    }
+
+    #[derive(ContentHash)]
+    #[allow(dead_code)]
+    struct Wrapper<T>(T, #[content_hash(skip)] u8);
+
+    #[derive(ContentHash)]
+    struct Unit;
+
+    #[derive(ContentHash)]
+    enum Shape {
+        Point,
+        Circle(u32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(ContentHash)]
+    enum Pinned {
+        #[content_hash(order = 2)]
+        Rect { width: u32, height: u32 },
+        #[content_hash(order = 0)]
+        Point,
+    }
+
+    #[derive(ContentHash)]
+    enum Never {}
+
+    #[test]
+    pub fn structs_hash_like_tuples() -> Result<(), HashError> {
+        let record = Record {
+            name: "file".into(),
+            size: 4,
+            tags: vec!["a".into(), "b".into()],
+        };
+
+        assert_eq!(
+            record.content_hash()?,
+            ("file", 4u64, vec!["a", "b"]).content_hash()?
+        );
+        assert_eq!(Unit.content_hash()?, ().content_hash()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn pinned_orders_and_skipped_fields() -> Result<(), HashError> {
+        let record = Record {
+            name: "file".into(),
+            size: 4,
+            tags: vec!["a".into()],
+        };
+        let reordered = Reordered {
+            tags: vec!["a".into()],
+            name: "file".into(),
+            cache: Some(1),
+            size: 4,
+        };
+
+        assert_eq!(reordered.content_hash()?, record.content_hash()?);
+        assert_eq!(
+            Wrapper(BTreeMap::from([(1u8, 'x')]), 1).content_hash()?,
+            Wrapper(BTreeMap::from([(1u8, 'x')]), 2).content_hash()?
+        );
+        assert_eq!(
+            Wrapper("text", 0).content_hash()?,
+            ("text",).content_hash()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn enum_variants_are_tagged() -> Result<(), HashError> {
+        let mut expected = 2u32.to_le_bytes().to_vec();
+
+        (3u32, 4u32).encode_content(&mut expected);
+
+        assert_eq!(
+            Shape::Rect {
+                width: 3,
+                height: 4
+            }
+            .content_hash()?,
+            crate::hash(&expected)?
+        );
+        assert_eq!(
+            Pinned::Rect {
+                width: 3,
+                height: 4
+            }
+            .content_hash()?,
+            crate::hash(&expected)?
+        );
+        assert_eq!(Shape::Point.content_hash()?, Pinned::Point.content_hash()?);
+        assert_ne!(
+            Shape::Point.content_hash()?,
+            Shape::Circle(0).content_hash()?
+        );
+
+        let _ = |never: &Never| never.content_hash();
+
+        Ok(())
+    }
+}